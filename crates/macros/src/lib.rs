@@ -65,6 +65,59 @@ pub fn manifold_warp(_attr: TokenStream, input: TokenStream) -> TokenStream {
     output.into()
 }
 
+#[proc_macro_attribute]
+pub fn manifold_sdf(_attr: TokenStream, input: TokenStream) -> TokenStream {
+    let structt = syn::parse_macro_input!(input as ItemStruct);
+    let struct_ident = structt.ident.clone();
+    let struct_name = struct_ident.to_string();
+
+    let unique_id = match UNIQUE_COUNTER.lock() {
+        Ok(guard) => guard.fetch_add(1, std::sync::atomic::Ordering::SeqCst),
+        Err(e) => panic!("Could not lock unique counter: {}", e),
+    };
+
+    let extern_c_fn_ident = proc_macro2::Ident::new(
+        format!(
+            "manifold3d_manifold_sdf_fn_{}_{}",
+            struct_name.to_ascii_lowercase(),
+            unique_id
+        )
+        .as_str(),
+        proc_macro2::Span::call_site(),
+    );
+
+    let output = quote!(
+        #structt
+
+        const _: () = {
+            use manifold3d::manifold::SignedDistance;
+
+            #[no_mangle]
+            #[doc(hidden)]
+            pub unsafe extern "C" fn #extern_c_fn_ident(
+                x: f64,
+                y: f64,
+                z: f64,
+                ctx: *mut ::std::os::raw::c_void
+            ) -> f64 {
+                let sdf = &*(ctx as *const #struct_ident);
+                sdf.signed_distance(manifold3d::types::Point3::new(x, y, z))
+            }
+
+            #[automatically_derived]
+            impl manifold3d::manifold::ExternCSdfFn for #struct_ident {
+                fn extern_c_sdf_fn(&self) -> manifold3d::manifold::ExternCSdfFnPtr {
+                    #extern_c_fn_ident
+                }
+            }
+        };
+
+        #[automatically_derived]
+        impl manifold3d::manifold::Sdf for #struct_ident {}
+    );
+    output.into()
+}
+
 #[proc_macro_attribute]
 pub fn manifold_manage_vertex_properties(_attr: TokenStream, input: TokenStream) -> TokenStream {
     let structt = syn::parse_macro_input!(input as ItemStruct);
@@ -31,6 +31,10 @@ impl BoundingBox {
         BoundingBox(ptr)
     }
 
+    pub(crate) fn ptr(&self) -> *mut ManifoldBox {
+        self.0
+    }
+
     pub fn min_point(&self) -> Point3 {
         unsafe { Point3::from(manifold_box_min(self.0)) }
     }
@@ -138,6 +142,21 @@ impl BoundingBox {
     }
 }
 
+impl Clone for BoundingBox {
+    fn clone(&self) -> Self {
+        BoundingBox::new(self.min_point(), self.max_point())
+    }
+}
+
+impl std::fmt::Debug for BoundingBox {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BoundingBox")
+            .field("min_point", &self.min_point())
+            .field("max_point", &self.max_point())
+            .finish()
+    }
+}
+
 impl Drop for BoundingBox {
     fn drop(&mut self) {
         unsafe {
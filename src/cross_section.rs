@@ -1,4 +1,9 @@
-use manifold3d_sys::ManifoldCrossSection;
+use crate::Polygons;
+use manifold3d_sys::{
+    manifold_alloc_polygons, manifold_cross_section_to_polygons, manifold_delete_cross_section,
+    ManifoldCrossSection,
+};
+use std::os::raw::c_void;
 
 pub struct CrossSection(*mut ManifoldCrossSection);
 
@@ -6,4 +11,21 @@ impl CrossSection {
     pub(crate) fn from_ptr(ptr: *mut ManifoldCrossSection) -> CrossSection {
         CrossSection(ptr)
     }
+
+    /// Returns the contours of the cross-section as [`Polygons`].
+    ///
+    /// Outer contours are oriented counter-clockwise and holes clockwise, so the
+    /// result can be fed straight into [`Polygons::extrude`] or [`Polygons::revolve`].
+    pub fn to_polygons(&self) -> Polygons {
+        let polygons_ptr = unsafe {
+            manifold_cross_section_to_polygons(manifold_alloc_polygons() as *mut c_void, self.0)
+        };
+        Polygons::from_ptr(polygons_ptr)
+    }
+}
+
+impl Drop for CrossSection {
+    fn drop(&mut self) {
+        unsafe { manifold_delete_cross_section(self.0) }
+    }
 }
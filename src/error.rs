@@ -16,6 +16,7 @@ pub enum Error {
     RunIndexWrongLength,
     FaceIdWrongLength,
     InvalidConstruction,
+    TangentsWrongLength,
     Unknown(u32),
 }
 
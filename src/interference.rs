@@ -0,0 +1,138 @@
+//! Interference detection between the parts of an assembly.
+
+use crate::types::{NonNegativeF64, PositiveF64};
+use crate::{BoundingBox, Manifold};
+
+/// Two manifolds which overlap.
+#[derive(Debug, Clone)]
+pub struct Overlap {
+    /// The index of the first manifold, which is always smaller than the second.
+    pub first: usize,
+    pub second: usize,
+    /// The volume of the intersection of both manifolds.
+    pub volume: f64,
+    /// The bounding box of the intersection of both manifolds.
+    pub bounding_box: BoundingBox,
+}
+
+/// Two manifolds which do not overlap, but are closer to each other than the clearance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NearMiss {
+    /// The index of the first manifold, which is always smaller than the second.
+    pub first: usize,
+    pub second: usize,
+    /// The minimum distance between both manifolds, zero if they touch.
+    pub gap: f64,
+}
+
+/// The result of [`check`].
+#[derive(Debug, Clone)]
+pub struct InterferenceReport {
+    /// The overlapping pairs, ordered by their indices.
+    pub overlaps: Vec<Overlap>,
+    /// The pairs closer than the clearance, ordered by their indices.
+    pub near_misses: Vec<NearMiss>,
+}
+
+impl InterferenceReport {
+    /// Returns whether no manifolds overlap or come closer than the clearance.
+    pub fn is_clear(&self) -> bool {
+        self.overlaps.is_empty() && self.near_misses.is_empty()
+    }
+}
+
+/// Checks all pairs of the given manifolds for overlaps and near-misses.
+///
+/// Pairs whose bounding boxes, enlarged by the clearance, do not overlap are culled with a
+/// sweep along the X axis and [`BoundingBox::overlaps_bounding_box`], so only nearby pairs
+/// are intersected. Pairs which do not overlap are reported as near-misses if
+/// [`Manifold::minimum_gap`] is smaller than the clearance. A clearance of zero skips the
+/// near-miss check.
+///
+/// # Examples
+/// ```
+/// use manifold3d::interference;
+/// use manifold3d::types::{NonNegativeF64, PositiveF64, Vec3};
+/// use manifold3d::Manifold;
+///
+/// let part = Manifold::new_cuboid(
+///     PositiveF64::new(1.0).unwrap(),
+///     PositiveF64::new(1.0).unwrap(),
+///     PositiveF64::new(1.0).unwrap(),
+///     false,
+/// );
+/// let parts = [
+///     part.clone(),
+///     part.translate(Vec3::new(0.5, 0.0, 0.0)),
+///     part.translate(Vec3::new(1.6, 0.0, 0.0)),
+///     part.translate(Vec3::new(10.0, 0.0, 0.0)),
+/// ];
+///
+/// let report = interference::check(&parts, NonNegativeF64::new(0.2).unwrap());
+/// assert_eq!(report.overlaps.len(), 1);
+/// assert!((report.overlaps[0].volume - 0.5).abs() < 1e-9);
+/// assert_eq!(report.near_misses.len(), 1);
+/// assert_eq!((report.near_misses[0].first, report.near_misses[0].second), (1, 2));
+/// ```
+pub fn check(manifolds: &[Manifold], clearance: NonNegativeF64) -> InterferenceReport {
+    let clearance = clearance.get();
+    let bounds = manifolds
+        .iter()
+        .map(|manifold| {
+            let bounding_box = manifold.bounding_box();
+            BoundingBox::new(
+                bounding_box.min_point() - clearance,
+                bounding_box.max_point() + clearance,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut report = InterferenceReport {
+        overlaps: Vec::new(),
+        near_misses: Vec::new(),
+    };
+    for (first, second) in candidate_pairs(manifolds, &bounds) {
+        let intersection = manifolds[first].intersection(&manifolds[second]);
+        if !intersection.is_empty() {
+            report.overlaps.push(Overlap {
+                first,
+                second,
+                volume: intersection.volume(),
+                bounding_box: intersection.bounding_box(),
+            });
+        } else if clearance > 0.0 {
+            let gap = manifolds[first]
+                .minimum_gap(&manifolds[second], PositiveF64::new(clearance).unwrap());
+            if gap < clearance {
+                report.near_misses.push(NearMiss { first, second, gap });
+            }
+        }
+    }
+    report
+}
+
+/// Returns the pairs of non-empty manifolds whose bounds overlap, ordered by their indices.
+fn candidate_pairs(manifolds: &[Manifold], bounds: &[BoundingBox]) -> Vec<(usize, usize)> {
+    let x_ranges = bounds
+        .iter()
+        .map(|bounding_box| (bounding_box.min_point().x, bounding_box.max_point().x))
+        .collect::<Vec<_>>();
+    let mut order = (0..manifolds.len())
+        .filter(|&index| !manifolds[index].is_empty())
+        .collect::<Vec<_>>();
+    order.sort_by(|&a, &b| x_ranges[a].0.total_cmp(&x_ranges[b].0));
+
+    let mut pairs = Vec::new();
+    let mut active: Vec<usize> = Vec::new();
+    for index in order {
+        active.retain(|&other| x_ranges[other].1 >= x_ranges[index].0);
+        for &other in &active {
+            if bounds[index].overlaps_bounding_box(&bounds[other]) {
+                pairs.push((index.min(other), index.max(other)));
+            }
+        }
+        active.push(index);
+    }
+    pairs.sort_unstable();
+    pairs
+}
@@ -0,0 +1,135 @@
+//! Helpers shared by the file formats working on 2D contours.
+
+use crate::types::{Point2, PositiveF64};
+use crate::{get_circular_segments, Polygons, SimplePolygon};
+use std::f64::consts::TAU;
+
+pub(crate) fn contours_of(polygons: &Polygons) -> Vec<Vec<Point2>> {
+    polygons
+        .as_vec()
+        .iter()
+        .map(|simple_polygon| simple_polygon.as_vec())
+        .filter(|points| !points.is_empty())
+        .collect()
+}
+
+/// Builds polygons from closed contours, dropping degenerate ones and orienting the rest
+/// by their nesting depth.
+pub(crate) fn into_polygons(contours: Vec<Vec<Point2>>) -> Polygons {
+    let contours = orient_by_nesting(contours);
+    Polygons::from_simple_polygons(
+        contours
+            .into_iter()
+            .map(SimplePolygon::new_from_points)
+            .collect(),
+    )
+}
+
+/// Orients contours counter-clockwise at even and clockwise at odd nesting depths.
+fn orient_by_nesting(contours: Vec<Vec<Point2>>) -> Vec<Vec<Point2>> {
+    let contours = contours
+        .into_iter()
+        .filter(|contour| contour.len() >= 3 && signed_area(contour) != 0.0)
+        .collect::<Vec<_>>();
+
+    let depths = contours
+        .iter()
+        .enumerate()
+        .map(|(index, contour)| {
+            contours
+                .iter()
+                .enumerate()
+                .filter(|(other_index, other)| {
+                    *other_index != index && contains_point(other, contour[0])
+                })
+                .count()
+        })
+        .collect::<Vec<_>>();
+
+    contours
+        .into_iter()
+        .zip(depths)
+        .map(|(mut contour, depth)| {
+            let counter_clockwise = signed_area(&contour) > 0.0;
+            if counter_clockwise != (depth % 2 == 0) {
+                contour.reverse();
+            }
+            contour
+        })
+        .collect()
+}
+
+fn signed_area(contour: &[Point2]) -> f64 {
+    let mut area = 0.0;
+    for (index, point) in contour.iter().enumerate() {
+        let next = contour[(index + 1) % contour.len()];
+        area += point.x * next.y - next.x * point.y;
+    }
+    area / 2.0
+}
+
+fn contains_point(contour: &[Point2], point: Point2) -> bool {
+    let mut inside = false;
+    let mut previous = contour[contour.len() - 1];
+    for &current in contour {
+        if (current.y > point.y) != (previous.y > point.y)
+            && point.x
+                < (previous.x - current.x) * (point.y - current.y) / (previous.y - current.y)
+                    + current.x
+        {
+            inside = !inside;
+        }
+        previous = current;
+    }
+    inside
+}
+
+/// Returns the segment count of an arc, following the global circular quality settings.
+pub(crate) fn arc_segments(radius: f64, angle: f64) -> usize {
+    let Ok(radius) = PositiveF64::new(radius) else {
+        return 1;
+    };
+    let full_circle_segments = get_circular_segments(radius).get() as f64;
+    ((full_circle_segments * angle / TAU).ceil() as usize).max(1)
+}
+
+/// Estimates the segment count of a Bézier curve from its control polygon, which is
+/// treated as a circular arc of the polygon's length and total turning angle. The length
+/// is multiplied by the scale factor of the transformation applied to the curve.
+pub(crate) fn curve_segments(control_points: &[Point2], scale_factor: f64) -> usize {
+    let mut length = 0.0;
+    let mut turning = 0.0;
+    let mut previous_direction: Option<f64> = None;
+    for pair in control_points.windows(2) {
+        let (dx, dy) = (pair[1].x - pair[0].x, pair[1].y - pair[0].y);
+        let edge_length = dx.hypot(dy);
+        if edge_length == 0.0 {
+            continue;
+        }
+        length += edge_length;
+        let direction = dy.atan2(dx);
+        if let Some(previous_direction) = previous_direction {
+            let mut change = (direction - previous_direction).abs() % TAU;
+            if change > TAU / 2.0 {
+                change = TAU - change;
+            }
+            turning += change;
+        }
+        previous_direction = Some(direction);
+    }
+
+    if turning < 1e-9 {
+        return 1;
+    }
+    arc_segments(length / turning * scale_factor, turning)
+}
+
+/// Formats a number with at most six decimals and without trailing zeros.
+pub(crate) fn number(value: f64) -> String {
+    let formatted = format!("{:.6}", value);
+    let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+    match formatted {
+        "-0" | "" => "0".to_string(),
+        _ => formatted.to_string(),
+    }
+}
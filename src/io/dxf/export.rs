@@ -0,0 +1,137 @@
+use crate::io::contour::{contours_of, number};
+use crate::types::Point2;
+use crate::{CrossSection, Polygons};
+use std::fmt::{Display, Write};
+
+/// A named set of contours in a multi-layer drawing.
+pub struct DxfLayer<'a> {
+    pub name: String,
+    pub polygons: &'a Polygons,
+}
+
+impl<'a> DxfLayer<'a> {
+    pub fn new(name: impl Into<String>, polygons: &'a Polygons) -> Self {
+        Self {
+            name: name.into(),
+            polygons,
+        }
+    }
+}
+
+/// Writes the given polygons as closed polylines on the default layer `0`.
+///
+/// # Examples
+/// ```
+/// use manifold3d::io::dxf::polygons_to_dxf;
+/// use manifold3d::types::PositiveF64;
+/// use manifold3d::Manifold;
+///
+/// let manifold = Manifold::new_cuboid(
+///     PositiveF64::new(1.0).unwrap(),
+///     PositiveF64::new(1.0).unwrap(),
+///     PositiveF64::new(1.0).unwrap(),
+///     true,
+/// );
+/// let slice = manifold.slice_by_height(PositiveF64::new(0.25).unwrap());
+/// let dxf = polygons_to_dxf(&slice);
+/// assert!(dxf.ends_with("EOF\n"));
+/// ```
+pub fn polygons_to_dxf(polygons: &Polygons) -> String {
+    layers_to_dxf(&[DxfLayer::new("0", polygons)])
+}
+
+/// Writes the contours of the given cross-section as closed polylines on the default
+/// layer `0`.
+pub fn cross_section_to_dxf(cross_section: &CrossSection) -> String {
+    polygons_to_dxf(&cross_section.to_polygons())
+}
+
+/// Writes several sets of polygons into one drawing, each on its own layer.
+///
+/// Characters which are not allowed in DXF layer names are replaced by underscores.
+/// Layers sharing a name after that are merged.
+pub fn layers_to_dxf(layers: &[DxfLayer]) -> String {
+    let names = layers
+        .iter()
+        .map(|layer| layer_name(&layer.name))
+        .collect::<Vec<_>>();
+    let mut unique_names: Vec<&String> = Vec::new();
+    for name in &names {
+        if !unique_names.contains(&name) {
+            unique_names.push(name);
+        }
+    }
+
+    let mut dxf = String::new();
+    group(&mut dxf, 0, "SECTION");
+    group(&mut dxf, 2, "HEADER");
+    group(&mut dxf, 9, "$ACADVER");
+    group(&mut dxf, 1, "AC1009");
+    group(&mut dxf, 0, "ENDSEC");
+
+    group(&mut dxf, 0, "SECTION");
+    group(&mut dxf, 2, "TABLES");
+    group(&mut dxf, 0, "TABLE");
+    group(&mut dxf, 2, "LAYER");
+    group(&mut dxf, 70, unique_names.len());
+    for name in &unique_names {
+        group(&mut dxf, 0, "LAYER");
+        group(&mut dxf, 2, name);
+        group(&mut dxf, 70, 0);
+        group(&mut dxf, 62, 7);
+        group(&mut dxf, 6, "CONTINUOUS");
+    }
+    group(&mut dxf, 0, "ENDTAB");
+    group(&mut dxf, 0, "ENDSEC");
+
+    group(&mut dxf, 0, "SECTION");
+    group(&mut dxf, 2, "ENTITIES");
+    for (layer, name) in layers.iter().zip(&names) {
+        for contour in contours_of(layer.polygons) {
+            write_polyline(&mut dxf, name, &contour);
+        }
+    }
+    group(&mut dxf, 0, "ENDSEC");
+    group(&mut dxf, 0, "EOF");
+    dxf
+}
+
+fn write_polyline(dxf: &mut String, layer: &str, contour: &[Point2]) {
+    group(dxf, 0, "POLYLINE");
+    group(dxf, 8, layer);
+    group(dxf, 66, 1);
+    group(dxf, 10, 0);
+    group(dxf, 20, 0);
+    group(dxf, 30, 0);
+    // Closed polyline
+    group(dxf, 70, 1);
+    for point in contour {
+        group(dxf, 0, "VERTEX");
+        group(dxf, 8, layer);
+        group(dxf, 10, number(point.x));
+        group(dxf, 20, number(point.y));
+        group(dxf, 30, 0);
+    }
+    group(dxf, 0, "SEQEND");
+    group(dxf, 8, layer);
+}
+
+fn group(dxf: &mut String, code: i32, value: impl Display) {
+    let _ = write!(dxf, "{:>3}\n{}\n", code, value);
+}
+
+fn layer_name(name: &str) -> String {
+    let name = name
+        .chars()
+        .map(|c| match c {
+            '<' | '>' | '/' | '\\' | '"' | ':' | ';' | '?' | '*' | '|' | '=' | '`' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect::<String>();
+    if name.trim().is_empty() {
+        "0".to_string()
+    } else {
+        name
+    }
+}
@@ -0,0 +1,413 @@
+use crate::io::contour::{arc_segments, into_polygons};
+use crate::types::Point2;
+use crate::Polygons;
+use std::collections::BTreeMap;
+use std::f64::consts::TAU;
+use thiserror::Error;
+
+/// The distance below which segment end points are considered to be the same point.
+pub const DXF_CHAIN_TOLERANCE: f64 = 1e-6;
+
+/// Errors that can occur while importing DXF data.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum DxfImportError {
+    #[error("invalid group code '{code}' on line {line}")]
+    InvalidGroupCode { code: String, line: usize },
+    #[error("group code {code} on line {line} has no value")]
+    MissingValue { code: i32, line: usize },
+    #[error("value '{value}' of group code {code} on line {line} is not a number")]
+    InvalidNumber {
+        code: i32,
+        value: String,
+        line: usize,
+    },
+    #[error("the drawing has no ENTITIES section")]
+    MissingEntities,
+}
+
+/// Imports the outlines of an ASCII DXF drawing.
+///
+/// `LWPOLYLINE`, `POLYLINE`, `LINE`, `ARC` and `CIRCLE` entities of the `ENTITIES`
+/// section are read, all other entities are ignored. Closed polylines and circles become
+/// contours on their own, while lines, arcs and open polylines are chained into closed
+/// loops by joining end points closer than [`DXF_CHAIN_TOLERANCE`]. Only entities on the
+/// same layer are chained together. Pieces which cannot be chained into a closed loop, like
+/// construction lines and center marks, are skipped, as are lines of zero length.
+///
+/// Arcs and polyline bulges are flattened with the global circular quality settings, see
+/// [`crate::get_circular_segments`]. Contours are oriented by their nesting depth,
+/// counter-clockwise for outlines and clockwise for holes.
+///
+/// # Examples
+/// ```
+/// use manifold3d::io::dxf::polygons_from_dxf;
+///
+/// // A 10 by 10 square drawn with four lines
+/// let mut document = String::from("0\nSECTION\n2\nENTITIES\n");
+/// for (x1, y1, x2, y2) in [(0, 0, 10, 0), (10, 0, 10, 10), (0, 10, 10, 10), (0, 10, 0, 0)] {
+///     document += &format!("0\nLINE\n8\n0\n10\n{x1}\n20\n{y1}\n11\n{x2}\n21\n{y2}\n");
+/// }
+/// document += "0\nENDSEC\n0\nEOF\n";
+///
+/// let polygons = polygons_from_dxf(&document).unwrap();
+/// assert_eq!(polygons.count(), 1);
+/// assert_eq!(polygons.get(0).unwrap().point_count(), 4);
+/// ```
+pub fn polygons_from_dxf(document: &str) -> Result<Polygons, DxfImportError> {
+    let groups = parse_groups(document)?;
+    let entities = entities_section(&groups).ok_or(DxfImportError::MissingEntities)?;
+
+    let mut contours = Vec::new();
+    // Open pieces by layer, chained once all entities have been read
+    let mut open_pieces: BTreeMap<&str, Vec<Vec<Point2>>> = BTreeMap::new();
+
+    let mut entities = split_entities(entities).into_iter().peekable();
+    while let Some(entity) = entities.next() {
+        let piece = match entity.kind {
+            "LINE" => Some(Piece::open(vec![
+                Point2::new(entity.number(10)?, entity.number(20)?),
+                Point2::new(entity.number(11)?, entity.number(21)?),
+            ])),
+            "ARC" => {
+                let center = Point2::new(entity.number(10)?, entity.number(20)?);
+                let start = entity.number(50)?.to_radians();
+                let mut sweep = entity.number(51)?.to_radians() - start;
+                if sweep <= 0.0 {
+                    sweep += TAU;
+                }
+                let points = arc_points(center, entity.number(40)?, start, sweep);
+                Some(Piece::open(entity.to_world(points)?))
+            }
+            "CIRCLE" => {
+                let center = Point2::new(entity.number(10)?, entity.number(20)?);
+                let mut points = arc_points(center, entity.number(40)?, 0.0, TAU);
+                points.pop();
+                Some(Piece::closed(entity.to_world(points)?))
+            }
+            "LWPOLYLINE" => {
+                let mut vertices: Vec<(Point2, f64)> = Vec::new();
+                for group in entity.groups {
+                    match group.code {
+                        10 => vertices.push((Point2::new(group.number()?, 0.0), 0.0)),
+                        20 => {
+                            if let Some(vertex) = vertices.last_mut() {
+                                vertex.0.y = group.number()?;
+                            }
+                        }
+                        42 => {
+                            if let Some(vertex) = vertices.last_mut() {
+                                vertex.1 = group.number()?;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                let closed = entity.flags()? & 1 != 0;
+                Some(Piece::new(
+                    entity.to_world(polyline_points(&vertices, closed))?,
+                    closed,
+                ))
+            }
+            "POLYLINE" => {
+                let mut vertices = Vec::new();
+                while let Some(vertex) = entities.next_if(|vertex| vertex.kind == "VERTEX") {
+                    vertices.push((
+                        Point2::new(vertex.number(10)?, vertex.number(20)?),
+                        vertex.number(42)?,
+                    ));
+                }
+                entities.next_if(|end| end.kind == "SEQEND");
+                let flags = entity.flags()?;
+                // Polygon and polyface meshes do not describe outlines
+                if flags & (16 | 64) != 0 {
+                    None
+                } else {
+                    let closed = flags & 1 != 0;
+                    Some(Piece::new(
+                        entity.to_world(polyline_points(&vertices, closed))?,
+                        closed,
+                    ))
+                }
+            }
+            _ => None,
+        };
+
+        if let Some(mut piece) = piece {
+            remove_duplicate_points(&mut piece.points);
+            // Single points, e.g. of lines with zero length, cannot outline anything
+            if piece.points.len() < 2 {
+                continue;
+            }
+            if piece.closed {
+                contours.push(piece.points);
+            } else {
+                open_pieces
+                    .entry(entity.layer())
+                    .or_default()
+                    .push(piece.points);
+            }
+        }
+    }
+
+    for pieces in open_pieces.into_values() {
+        contours.extend(chain(pieces));
+    }
+
+    Ok(into_polygons(contours))
+}
+
+struct Piece {
+    points: Vec<Point2>,
+    closed: bool,
+}
+
+impl Piece {
+    fn new(points: Vec<Point2>, closed: bool) -> Self {
+        Self { points, closed }
+    }
+
+    fn open(points: Vec<Point2>) -> Self {
+        Self::new(points, false)
+    }
+
+    fn closed(points: Vec<Point2>) -> Self {
+        Self::new(points, true)
+    }
+}
+
+/// A group code and its value.
+struct Group<'a> {
+    code: i32,
+    value: &'a str,
+    line: usize,
+}
+
+impl Group<'_> {
+    fn number(&self) -> Result<f64, DxfImportError> {
+        self.value
+            .parse()
+            .map_err(|_| DxfImportError::InvalidNumber {
+                code: self.code,
+                value: self.value.to_string(),
+                line: self.line + 1,
+            })
+    }
+}
+
+/// The groups of one entity, following its type.
+struct Entity<'a, 'b> {
+    kind: &'a str,
+    groups: &'b [Group<'a>],
+}
+
+impl<'a> Entity<'a, '_> {
+    fn optional_number(&self, code: i32) -> Result<Option<f64>, DxfImportError> {
+        self.groups
+            .iter()
+            .find(|group| group.code == code)
+            .map(Group::number)
+            .transpose()
+    }
+
+    /// Returns the value of the group, which defaults to zero like in DXF.
+    fn number(&self, code: i32) -> Result<f64, DxfImportError> {
+        Ok(self.optional_number(code)?.unwrap_or(0.0))
+    }
+
+    fn flags(&self) -> Result<i64, DxfImportError> {
+        Ok(self.number(70)? as i64)
+    }
+
+    fn layer(&self) -> &'a str {
+        self.groups
+            .iter()
+            .find(|group| group.code == 8)
+            .map_or("0", |group| group.value)
+    }
+
+    /// Converts points from the entity's object coordinate system to world coordinates.
+    ///
+    /// Only the extrusion directions of 2D drawings, along the positive or negative Z
+    /// axis, are supported. The latter mirrors the X axis.
+    fn to_world(&self, mut points: Vec<Point2>) -> Result<Vec<Point2>, DxfImportError> {
+        if self.optional_number(230)?.is_some_and(|z| z < 0.0) {
+            for point in points.iter_mut() {
+                point.x = -point.x;
+            }
+        }
+        Ok(points)
+    }
+}
+
+fn parse_groups(document: &str) -> Result<Vec<Group<'_>>, DxfImportError> {
+    let mut lines = document.lines().enumerate();
+    let mut groups = Vec::new();
+    while let Some((line, code)) = lines.next() {
+        let code = code.trim();
+        if code.is_empty() {
+            continue;
+        }
+        let code = code.parse().map_err(|_| DxfImportError::InvalidGroupCode {
+            code: code.to_string(),
+            line: line + 1,
+        })?;
+        let (_, value) = lines.next().ok_or(DxfImportError::MissingValue {
+            code,
+            line: line + 1,
+        })?;
+        groups.push(Group {
+            code,
+            value: value.trim(),
+            line: line + 1,
+        });
+    }
+    Ok(groups)
+}
+
+/// Returns the groups between the start and the end of the `ENTITIES` section.
+fn entities_section<'a, 'b>(groups: &'b [Group<'a>]) -> Option<&'b [Group<'a>]> {
+    let start = groups.windows(2).position(|pair| {
+        pair[0].code == 0
+            && pair[0].value == "SECTION"
+            && pair[1].code == 2
+            && pair[1].value == "ENTITIES"
+    })? + 2;
+    let length = groups[start..]
+        .iter()
+        .position(|group| group.code == 0 && group.value == "ENDSEC")
+        .unwrap_or(groups.len() - start);
+    Some(&groups[start..start + length])
+}
+
+fn split_entities<'a, 'b>(groups: &'b [Group<'a>]) -> Vec<Entity<'a, 'b>> {
+    let mut entities = Vec::new();
+    let mut start = 0;
+    while start < groups.len() {
+        let length = groups[start + 1..]
+            .iter()
+            .position(|group| group.code == 0)
+            .map_or(groups.len() - start, |length| length + 1);
+        entities.push(Entity {
+            kind: groups[start].value,
+            groups: &groups[start + 1..start + length],
+        });
+        start += length;
+    }
+    entities
+}
+
+fn arc_points(center: Point2, radius: f64, start: f64, sweep: f64) -> Vec<Point2> {
+    let segments = arc_segments(radius, sweep.abs());
+    (0..=segments)
+        .map(|i| {
+            let angle = start + sweep * i as f64 / segments as f64;
+            Point2::new(
+                center.x + radius * angle.cos(),
+                center.y + radius * angle.sin(),
+            )
+        })
+        .collect()
+}
+
+/// Flattens polyline vertices, replacing segments with a bulge by arcs.
+///
+/// The bulge of a vertex is the tangent of a quarter of the included angle of the arc
+/// to the next vertex, positive for counter-clockwise arcs.
+fn polyline_points(vertices: &[(Point2, f64)], closed: bool) -> Vec<Point2> {
+    let mut points = Vec::new();
+    let segment_count = if closed {
+        vertices.len()
+    } else {
+        vertices.len().saturating_sub(1)
+    };
+    if let Some((first, _)) = vertices.first() {
+        points.push(*first);
+    }
+
+    for index in 0..segment_count {
+        let (from, bulge) = vertices[index];
+        let (to, _) = vertices[(index + 1) % vertices.len()];
+        if bulge != 0.0 && from != to {
+            let included_angle = 4.0 * bulge.atan();
+            let chord = to - from;
+            let chord_length = chord.x.hypot(chord.y);
+            let radius = chord_length / (2.0 * (included_angle / 2.0).sin()).abs();
+            // Distance of the center from the chord's midpoint, to its left
+            let offset = chord_length / 2.0 * (1.0 - bulge * bulge) / (2.0 * bulge);
+            let center = Point2::new(
+                (from.x + to.x) / 2.0 - chord.y / chord_length * offset,
+                (from.y + to.y) / 2.0 + chord.x / chord_length * offset,
+            );
+            let start = (from.y - center.y).atan2(from.x - center.x);
+            let arc = arc_points(center, radius, start, included_angle);
+            points.extend(&arc[1..arc.len() - 1]);
+        }
+        if !closed || index + 1 < segment_count {
+            points.push(to);
+        }
+    }
+    points
+}
+
+fn is_same_point(a: Point2, b: Point2) -> bool {
+    (a.x - b.x).hypot(a.y - b.y) <= DXF_CHAIN_TOLERANCE
+}
+
+fn remove_duplicate_points(points: &mut Vec<Point2>) {
+    points.dedup_by(|a, b| is_same_point(*a, *b));
+}
+
+/// Joins open pieces at matching end points into closed loops, dropping the pieces which
+/// do not end up in one.
+fn chain(mut pieces: Vec<Vec<Point2>>) -> Vec<Vec<Point2>> {
+    // Pieces with a loose end can never be part of a loop, and chaining them would lead
+    // loops they touch into a dead end
+    loop {
+        let ends = pieces
+            .iter()
+            .map(|piece| [piece[0], piece[piece.len() - 1]])
+            .collect::<Vec<_>>();
+        let is_connected = |index: usize, end: Point2| {
+            is_same_point(ends[index][0], ends[index][1])
+                || ends.iter().enumerate().any(|(other, [start, last])| {
+                    other != index && (is_same_point(*start, end) || is_same_point(*last, end))
+                })
+        };
+        let count = pieces.len();
+        let mut index = 0;
+        pieces.retain(|_| {
+            let keep = ends[index].iter().all(|&end| is_connected(index, end));
+            index += 1;
+            keep
+        });
+        if pieces.len() == count {
+            break;
+        }
+    }
+
+    let mut loops = Vec::new();
+    while let Some(mut contour) = pieces.pop() {
+        loop {
+            let start = contour[0];
+            let end = contour[contour.len() - 1];
+            if contour.len() > 1 && is_same_point(start, end) {
+                contour.pop();
+                loops.push(contour);
+                break;
+            }
+
+            let next = pieces.iter().position(|piece| {
+                is_same_point(piece[0], end) || is_same_point(piece[piece.len() - 1], end)
+            });
+            let Some(next) = next else {
+                break;
+            };
+            let mut piece = pieces.swap_remove(next);
+            if !is_same_point(piece[0], end) {
+                piece.reverse();
+            }
+            contour.extend(piece.into_iter().skip(1));
+        }
+    }
+    loops
+}
@@ -0,0 +1,12 @@
+//! Conversion between 2D geometry and DXF drawings.
+//!
+//! Drawings are read from and written to the ASCII flavor of DXF. Contours are exported
+//! as closed R12 `POLYLINE` entities, which virtually every CAD, CAM and laser cutter
+//! software understands. A contour exported with [`polygons_to_dxf`] is imported again by
+//! [`polygons_from_dxf`] unchanged.
+
+mod export;
+mod import;
+
+pub use export::*;
+pub use import::*;
@@ -0,0 +1,5 @@
+//! Import and export of geometry in external file formats.
+
+pub(crate) mod contour;
+pub mod dxf;
+pub mod svg;
@@ -0,0 +1,208 @@
+use crate::io::contour::{contours_of, number};
+use crate::types::Point2;
+use crate::{CrossSection, FillRule, Polygons};
+use std::fmt::Write;
+
+/// The physical unit used for the `width` and `height` of the SVG document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SvgUnit {
+    Millimeter,
+    Centimeter,
+    Inch,
+    Point,
+    Pixel,
+}
+
+impl SvgUnit {
+    fn suffix(&self) -> &'static str {
+        match self {
+            SvgUnit::Millimeter => "mm",
+            SvgUnit::Centimeter => "cm",
+            SvgUnit::Inch => "in",
+            SvgUnit::Point => "pt",
+            SvgUnit::Pixel => "px",
+        }
+    }
+}
+
+/// Options controlling how contours are rendered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgOptions {
+    /// The fill rule used to decide which regions are inside.
+    ///
+    /// SVG only knows [`FillRule::EvenOdd`] and [`FillRule::NonZero`]. [`FillRule::Positive`]
+    /// and [`FillRule::Negative`] are rendered as non-zero, which is equivalent for
+    /// non-overlapping contours with counter-clockwise outlines and clockwise holes, as
+    /// produced by [`crate::Manifold::slice_by_height`].
+    pub fill_rule: FillRule,
+    /// The factor applied to model coordinates to get document units.
+    pub scale: f64,
+    /// The unit of the document dimensions.
+    pub unit: SvgUnit,
+    /// The space added around the contours, in model units.
+    pub margin: f64,
+    /// The fill color of the contours.
+    pub fill: String,
+    /// The stroke color of the contours.
+    pub stroke: String,
+    /// The stroke width in document units.
+    pub stroke_width: f64,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            fill_rule: FillRule::NonZero,
+            scale: 1.0,
+            unit: SvgUnit::Millimeter,
+            margin: 0.0,
+            fill: "none".to_string(),
+            stroke: "black".to_string(),
+            stroke_width: 0.1,
+        }
+    }
+}
+
+/// A named group of contours in a multi-layer document.
+pub struct SvgLayer<'a> {
+    pub label: String,
+    pub polygons: &'a Polygons,
+}
+
+impl<'a> SvgLayer<'a> {
+    pub fn new(label: impl Into<String>, polygons: &'a Polygons) -> Self {
+        Self {
+            label: label.into(),
+            polygons,
+        }
+    }
+}
+
+/// Renders the given polygons as a single SVG path.
+///
+/// # Examples
+/// ```
+/// use manifold3d::io::svg::{polygons_to_svg, SvgOptions};
+/// use manifold3d::types::PositiveF64;
+/// use manifold3d::Manifold;
+///
+/// let manifold = Manifold::new_cuboid(
+///     PositiveF64::new(1.0).unwrap(),
+///     PositiveF64::new(1.0).unwrap(),
+///     PositiveF64::new(1.0).unwrap(),
+///     true,
+/// );
+/// let slice = manifold.slice_by_height(PositiveF64::new(0.25).unwrap());
+/// let svg = polygons_to_svg(&slice, &SvgOptions::default());
+/// assert!(svg.starts_with("<?xml"));
+/// ```
+pub fn polygons_to_svg(polygons: &Polygons, options: &SvgOptions) -> String {
+    let contours = contours_of(polygons);
+    let mut svg = String::new();
+    write_header(&mut svg, bounds(contours.iter()), options);
+    write_path(&mut svg, &contours, options);
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Renders the contours of the given cross-section as a single SVG path.
+pub fn cross_section_to_svg(cross_section: &CrossSection, options: &SvgOptions) -> String {
+    polygons_to_svg(&cross_section.to_polygons(), options)
+}
+
+/// Renders several sets of polygons into one document, each inside its own layer group.
+///
+/// Layers are written in the given order, so later layers are drawn on top. The view
+/// box covers the contours of all layers.
+pub fn layers_to_svg(layers: &[SvgLayer], options: &SvgOptions) -> String {
+    let layer_contours = layers
+        .iter()
+        .map(|layer| contours_of(layer.polygons))
+        .collect::<Vec<_>>();
+
+    let mut svg = String::new();
+    write_header(&mut svg, bounds(layer_contours.iter().flatten()), options);
+    for (index, (layer, contours)) in layers.iter().zip(&layer_contours).enumerate() {
+        let _ = writeln!(
+            svg,
+            "<g id=\"layer-{}\" inkscape:groupmode=\"layer\" inkscape:label=\"{}\">",
+            index,
+            escape(&layer.label)
+        );
+        write_path(&mut svg, contours, options);
+        svg.push_str("</g>\n");
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn bounds<'a>(contours: impl Iterator<Item = &'a Vec<Point2>>) -> Option<(Point2, Point2)> {
+    contours.flatten().fold(None, |bounds, point| match bounds {
+        None => Some((*point, *point)),
+        Some((min, max)) => Some((
+            Point2::new(min.x.min(point.x), min.y.min(point.y)),
+            Point2::new(max.x.max(point.x), max.y.max(point.y)),
+        )),
+    })
+}
+
+fn write_header(svg: &mut String, bounds: Option<(Point2, Point2)>, options: &SvgOptions) {
+    let (min, max) = bounds.unwrap_or((Point2::new(0.0, 0.0), Point2::new(0.0, 0.0)));
+    let min = min - options.margin;
+    let max = max + options.margin;
+    let width = (max.x - min.x) * options.scale;
+    let height = (max.y - min.y) * options.scale;
+    let unit = options.unit.suffix();
+
+    svg.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"no\"?>\n");
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+         xmlns:inkscape=\"http://www.inkscape.org/namespaces/inkscape\" \
+         width=\"{}{unit}\" height=\"{}{unit}\" viewBox=\"{} {} {} {}\">",
+        number(width),
+        number(height),
+        number(min.x * options.scale),
+        number(-max.y * options.scale),
+        number(width),
+        number(height),
+    );
+}
+
+fn write_path(svg: &mut String, contours: &[Vec<Point2>], options: &SvgOptions) {
+    let mut data = String::new();
+    for contour in contours {
+        for (index, point) in contour.iter().enumerate() {
+            let _ = write!(
+                data,
+                "{}{},{} ",
+                if index == 0 { "M" } else { "L" },
+                number(point.x * options.scale),
+                number(-point.y * options.scale)
+            );
+        }
+        data.push_str("Z ");
+    }
+
+    let fill_rule = match options.fill_rule {
+        FillRule::EvenOdd => "evenodd",
+        _ => "nonzero",
+    };
+    let _ = writeln!(
+        svg,
+        "<path fill=\"{}\" fill-rule=\"{}\" stroke=\"{}\" stroke-width=\"{}\" d=\"{}\"/>",
+        escape(&options.fill),
+        fill_rule,
+        escape(&options.stroke),
+        number(options.stroke_width),
+        data.trim_end()
+    );
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
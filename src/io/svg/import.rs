@@ -0,0 +1,799 @@
+use crate::io::contour::{arc_segments, curve_segments, into_polygons};
+use crate::types::Point2;
+use crate::Polygons;
+use std::f64::consts::TAU;
+use thiserror::Error;
+
+/// Errors that can occur while importing SVG data.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum SvgImportError {
+    #[error("unexpected character '{character}' at offset {offset} of the path data")]
+    UnexpectedCharacter { character: char, offset: usize },
+    #[error("path command '{command}' is missing parameters")]
+    MissingParameters { command: char },
+    #[error("invalid transform '{0}'")]
+    InvalidTransform(String),
+    #[error("malformed SVG document: {0}")]
+    MalformedDocument(String),
+}
+
+/// Imports the outlines of all shapes of an SVG document.
+///
+/// Supported are `path`, `polygon`, `polyline`, `rect`, `circle` and `ellipse` elements,
+/// including `transform` attributes on them and on all enclosing groups. Polylines are
+/// closed implicitly like open subpaths. Shapes inside `defs`, `clipPath`, `mask`,
+/// `marker`, `pattern` and `symbol` elements are skipped. Coordinates are taken in user
+/// units, the document's `viewBox` and dimensions are ignored.
+///
+/// See [`polygons_from_svg_path`] for how curves are flattened and contours are oriented.
+///
+/// # Examples
+/// ```
+/// use manifold3d::io::svg::polygons_from_svg;
+///
+/// let document = r#"
+///     <svg xmlns="http://www.w3.org/2000/svg">
+///         <g transform="translate(10, 0)">
+///             <path d="M0,0 H10 V-10 H0 Z" />
+///         </g>
+///     </svg>
+/// "#;
+/// let polygons = polygons_from_svg(document).unwrap();
+/// assert_eq!(polygons.count(), 1);
+/// ```
+pub fn polygons_from_svg(document: &str) -> Result<Polygons, SvgImportError> {
+    let mut contours = Vec::new();
+    // Transform and whether geometry is rendered, for every open element
+    let mut stack: Vec<(Affine, bool)> = vec![(Affine::IDENTITY, true)];
+
+    for tag in Tags::new(document) {
+        let tag = tag?;
+        if tag.closing {
+            if stack.len() > 1 {
+                stack.pop();
+            }
+            continue;
+        }
+
+        let (parent_transform, parent_rendered) = *stack.last().unwrap();
+        let transform = match tag.attribute("transform") {
+            Some(transform) => parent_transform.then(&parse_transform(transform)?),
+            None => parent_transform,
+        };
+        let rendered = parent_rendered && !NON_RENDERED_ELEMENTS.contains(&tag.name);
+
+        if rendered {
+            if let Some(path_data) = shape_path_data(&tag) {
+                contours.extend(parse_path(&path_data, &transform)?);
+            }
+        }
+        if !tag.self_closing {
+            stack.push((transform, rendered));
+        }
+    }
+
+    Ok(into_polygons(contours))
+}
+
+/// Imports the outline described by SVG path data, as found in the `d` attribute of a
+/// `path` element.
+///
+/// All commands (`M`, `L`, `H`, `V`, `C`, `S`, `Q`, `T`, `A` and `Z`) are supported in
+/// their absolute and relative forms. Every subpath becomes one contour, open subpaths
+/// are closed implicitly.
+///
+/// Curves are flattened with the global quality settings, treating each curve as a
+/// circular arc whose segment count is given by [`crate::get_circular_segments`]. Lowering the
+/// minimum circular angle or edge length therefore produces finer outlines.
+///
+/// Contours are oriented by their nesting depth, counter-clockwise for outlines and
+/// clockwise for holes, so the result can be passed to [`Polygons::extrude`] or
+/// [`Polygons::revolve`] directly.
+///
+/// # Examples
+/// ```
+/// use manifold3d::io::svg::polygons_from_svg_path;
+///
+/// let polygons = polygons_from_svg_path("M0,0 h10 v-10 h-10 z M2,-2 h6 v-6 h-6 z").unwrap();
+/// assert_eq!(polygons.count(), 2);
+/// ```
+pub fn polygons_from_svg_path(path_data: &str) -> Result<Polygons, SvgImportError> {
+    Ok(into_polygons(parse_path(path_data, &Affine::IDENTITY)?))
+}
+
+const NON_RENDERED_ELEMENTS: [&str; 6] =
+    ["defs", "clipPath", "mask", "marker", "pattern", "symbol"];
+
+/// Converts the basic shapes to equivalent path data.
+fn shape_path_data(tag: &Tag) -> Option<String> {
+    let number = |name: &str| tag.attribute(name).and_then(parse_length).unwrap_or(0.0);
+    match tag.name {
+        "path" => tag.attribute("d").map(str::to_string),
+        "polygon" | "polyline" => tag
+            .attribute("points")
+            .map(|points| format!("M{}Z", points)),
+        "rect" => {
+            let (x, y, width, height) =
+                (number("x"), number("y"), number("width"), number("height"));
+            Some(format!("M{x},{y} h{width} v{height} h{} Z", -width))
+        }
+        "circle" => {
+            let r = number("r");
+            Some(ellipse_path_data(number("cx"), number("cy"), r, r))
+        }
+        "ellipse" => Some(ellipse_path_data(
+            number("cx"),
+            number("cy"),
+            number("rx"),
+            number("ry"),
+        )),
+        _ => None,
+    }
+}
+
+fn ellipse_path_data(cx: f64, cy: f64, rx: f64, ry: f64) -> String {
+    format!(
+        "M{},{cy} A{rx},{ry} 0 1 0 {},{cy} A{rx},{ry} 0 1 0 {},{cy} Z",
+        cx + rx,
+        cx - rx,
+        cx + rx
+    )
+}
+
+/// Parses a length attribute, ignoring a trailing `px` unit.
+fn parse_length(value: &str) -> Option<f64> {
+    value.trim().trim_end_matches("px").trim().parse().ok()
+}
+
+/// Parses path data into flattened contours in model space.
+fn parse_path(path_data: &str, transform: &Affine) -> Result<Vec<Vec<Point2>>, SvgImportError> {
+    let mut lexer = Lexer::new(path_data);
+    let mut builder = PathBuilder::new(transform);
+    let mut previous_command: Option<u8> = None;
+
+    while let Some(next) = lexer.peek() {
+        let command = if next.is_ascii_alphabetic() {
+            lexer.advance();
+            next
+        } else {
+            match previous_command {
+                // Coordinates following a move-to are implicit line-tos
+                Some(b'M') if lexer.at_number() => b'L',
+                Some(b'm') if lexer.at_number() => b'l',
+                Some(command) if lexer.at_number() && !command.eq_ignore_ascii_case(&b'z') => {
+                    command
+                }
+                _ => {
+                    return Err(SvgImportError::UnexpectedCharacter {
+                        character: path_data[lexer.position..].chars().next().unwrap(),
+                        offset: lexer.position,
+                    })
+                }
+            }
+        };
+        let relative = command.is_ascii_lowercase();
+        let missing = || SvgImportError::MissingParameters {
+            command: command as char,
+        };
+
+        match command.to_ascii_uppercase() {
+            b'M' => {
+                let to = lexer.point().ok_or_else(missing)?;
+                builder.move_to(builder.resolve(to, relative));
+            }
+            b'L' => {
+                let to = lexer.point().ok_or_else(missing)?;
+                builder.line_to(builder.resolve(to, relative));
+            }
+            b'H' => {
+                let x = lexer.number().ok_or_else(missing)?;
+                let x = if relative { builder.cursor.x + x } else { x };
+                builder.line_to(Point2::new(x, builder.cursor.y));
+            }
+            b'V' => {
+                let y = lexer.number().ok_or_else(missing)?;
+                let y = if relative { builder.cursor.y + y } else { y };
+                builder.line_to(Point2::new(builder.cursor.x, y));
+            }
+            b'C' => {
+                let points = lexer.points::<3>().ok_or_else(missing)?;
+                let [c1, c2, to] = points.map(|p| builder.resolve(p, relative));
+                builder.cubic_to(c1, c2, to);
+            }
+            b'S' => {
+                let points = lexer.points::<2>().ok_or_else(missing)?;
+                let [c2, to] = points.map(|p| builder.resolve(p, relative));
+                let c1 = match previous_command.map(|c| c.to_ascii_uppercase()) {
+                    Some(b'C') | Some(b'S') => builder.reflected_control(),
+                    _ => builder.cursor,
+                };
+                builder.cubic_to(c1, c2, to);
+            }
+            b'Q' => {
+                let points = lexer.points::<2>().ok_or_else(missing)?;
+                let [c, to] = points.map(|p| builder.resolve(p, relative));
+                builder.quadratic_to(c, to);
+            }
+            b'T' => {
+                let to = lexer.point().ok_or_else(missing)?;
+                let to = builder.resolve(to, relative);
+                let c = match previous_command.map(|c| c.to_ascii_uppercase()) {
+                    Some(b'Q') | Some(b'T') => builder.reflected_control(),
+                    _ => builder.cursor,
+                };
+                builder.quadratic_to(c, to);
+            }
+            b'A' => {
+                let rx = lexer.number().ok_or_else(missing)?;
+                let ry = lexer.number().ok_or_else(missing)?;
+                let rotation = lexer.number().ok_or_else(missing)?;
+                let large_arc = lexer.flag().ok_or_else(missing)?;
+                let sweep = lexer.flag().ok_or_else(missing)?;
+                let to = lexer.point().ok_or_else(missing)?;
+                builder.arc_to(
+                    rx,
+                    ry,
+                    rotation,
+                    large_arc,
+                    sweep,
+                    builder.resolve(to, relative),
+                );
+            }
+            b'Z' => builder.close(),
+            _ => {
+                return Err(SvgImportError::UnexpectedCharacter {
+                    character: command as char,
+                    offset: lexer.position - 1,
+                })
+            }
+        }
+        previous_command = Some(command);
+    }
+
+    Ok(builder.finish())
+}
+
+struct PathBuilder<'a> {
+    transform: &'a Affine,
+    contours: Vec<Vec<Point2>>,
+    current: Vec<Point2>,
+    cursor: Point2,
+    subpath_start: Point2,
+    last_control: Point2,
+}
+
+impl<'a> PathBuilder<'a> {
+    fn new(transform: &'a Affine) -> Self {
+        let origin = Point2::new(0.0, 0.0);
+        Self {
+            transform,
+            contours: Vec::new(),
+            current: Vec::new(),
+            cursor: origin,
+            subpath_start: origin,
+            last_control: origin,
+        }
+    }
+
+    fn resolve(&self, point: Point2, relative: bool) -> Point2 {
+        if relative {
+            self.cursor + point
+        } else {
+            point
+        }
+    }
+
+    fn reflected_control(&self) -> Point2 {
+        Point2::new(
+            2.0 * self.cursor.x - self.last_control.x,
+            2.0 * self.cursor.y - self.last_control.y,
+        )
+    }
+
+    fn push(&mut self, point: Point2) {
+        if self.current.is_empty() {
+            self.current.push(self.transform.apply(self.cursor));
+        }
+        self.current.push(self.transform.apply(point));
+    }
+
+    fn flush(&mut self) {
+        let mut contour = std::mem::take(&mut self.current);
+        // The closing point duplicates the start of the contour
+        if contour.len() > 1 && contour.first() == contour.last() {
+            contour.pop();
+        }
+        if !contour.is_empty() {
+            self.contours.push(contour);
+        }
+    }
+
+    fn move_to(&mut self, to: Point2) {
+        self.flush();
+        self.cursor = to;
+        self.subpath_start = to;
+        self.last_control = to;
+    }
+
+    fn line_to(&mut self, to: Point2) {
+        self.push(to);
+        self.cursor = to;
+        self.last_control = to;
+    }
+
+    fn cubic_to(&mut self, c1: Point2, c2: Point2, to: Point2) {
+        let from = self.cursor;
+        let segments = curve_segments(&[from, c1, c2, to], self.transform.scale_factor());
+        for i in 1..=segments {
+            let t = i as f64 / segments as f64;
+            let s = 1.0 - t;
+            let (w0, w1, w2, w3) = (s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t);
+            self.push(Point2::new(
+                w0 * from.x + w1 * c1.x + w2 * c2.x + w3 * to.x,
+                w0 * from.y + w1 * c1.y + w2 * c2.y + w3 * to.y,
+            ));
+        }
+        self.cursor = to;
+        self.last_control = c2;
+    }
+
+    fn quadratic_to(&mut self, c: Point2, to: Point2) {
+        let from = self.cursor;
+        let segments = curve_segments(&[from, c, to], self.transform.scale_factor());
+        for i in 1..=segments {
+            let t = i as f64 / segments as f64;
+            let s = 1.0 - t;
+            let (w0, w1, w2) = (s * s, 2.0 * s * t, t * t);
+            self.push(Point2::new(
+                w0 * from.x + w1 * c.x + w2 * to.x,
+                w0 * from.y + w1 * c.y + w2 * to.y,
+            ));
+        }
+        self.cursor = to;
+        self.last_control = c;
+    }
+
+    /// Flattens an elliptical arc, following the SVG endpoint to center conversion.
+    fn arc_to(
+        &mut self,
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        to: Point2,
+    ) {
+        let from = self.cursor;
+        let (mut rx, mut ry) = (rx.abs(), ry.abs());
+        if from == to {
+            return;
+        }
+        if rx == 0.0 || ry == 0.0 {
+            self.line_to(to);
+            return;
+        }
+
+        let (sin, cos) = rotation.to_radians().sin_cos();
+        let dx = (from.x - to.x) / 2.0;
+        let dy = (from.y - to.y) / 2.0;
+        let x1 = cos * dx + sin * dy;
+        let y1 = -sin * dx + cos * dy;
+
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+        if lambda > 1.0 {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+
+        let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+        let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+        let sign = if large_arc == sweep { -1.0 } else { 1.0 };
+        let coefficient = sign * (numerator / denominator).max(0.0).sqrt();
+        let cx1 = coefficient * rx * y1 / ry;
+        let cy1 = -coefficient * ry * x1 / rx;
+        let cx = cos * cx1 - sin * cy1 + (from.x + to.x) / 2.0;
+        let cy = sin * cx1 + cos * cy1 + (from.y + to.y) / 2.0;
+
+        let angle =
+            |ux: f64, uy: f64, vx: f64, vy: f64| (ux * vy - uy * vx).atan2(ux * vx + uy * vy);
+        let ux = (x1 - cx1) / rx;
+        let uy = (y1 - cy1) / ry;
+        let start_angle = angle(1.0, 0.0, ux, uy);
+        let mut sweep_angle = angle(ux, uy, (-x1 - cx1) / rx, (-y1 - cy1) / ry);
+        if !sweep && sweep_angle > 0.0 {
+            sweep_angle -= TAU;
+        } else if sweep && sweep_angle < 0.0 {
+            sweep_angle += TAU;
+        }
+
+        let radius = rx.max(ry) * self.transform.scale_factor();
+        let segments = arc_segments(radius, sweep_angle.abs());
+        for i in 1..segments {
+            let t = start_angle + sweep_angle * i as f64 / segments as f64;
+            let (sin_t, cos_t) = t.sin_cos();
+            self.push(Point2::new(
+                cx + rx * cos_t * cos - ry * sin_t * sin,
+                cy + rx * cos_t * sin + ry * sin_t * cos,
+            ));
+        }
+        self.line_to(to);
+    }
+
+    fn close(&mut self) {
+        self.flush();
+        self.cursor = self.subpath_start;
+        self.last_control = self.subpath_start;
+    }
+
+    fn finish(mut self) -> Vec<Vec<Point2>> {
+        self.flush();
+        self.contours
+    }
+}
+
+struct Lexer<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(data: &'a str) -> Self {
+        Self {
+            bytes: data.as_bytes(),
+            position: 0,
+        }
+    }
+
+    fn skip_separators(&mut self) {
+        while self.position < self.bytes.len()
+            && (self.bytes[self.position].is_ascii_whitespace()
+                || self.bytes[self.position] == b',')
+        {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_separators();
+        self.bytes.get(self.position).copied()
+    }
+
+    fn advance(&mut self) {
+        self.position += 1;
+    }
+
+    fn at_number(&mut self) -> bool {
+        matches!(self.peek(), Some(b'0'..=b'9' | b'.' | b'-' | b'+'))
+    }
+
+    fn number(&mut self) -> Option<f64> {
+        if !self.at_number() {
+            return None;
+        }
+        let start = self.position;
+        let mut end = start;
+        if matches!(self.bytes[end], b'-' | b'+') {
+            end += 1;
+        }
+        let mut seen_dot = false;
+        while end < self.bytes.len() {
+            match self.bytes[end] {
+                b'0'..=b'9' => end += 1,
+                b'.' if !seen_dot => {
+                    seen_dot = true;
+                    end += 1;
+                }
+                b'e' | b'E'
+                    if self
+                        .bytes
+                        .get(end + 1)
+                        .is_some_and(|b| b.is_ascii_digit() || *b == b'-' || *b == b'+') =>
+                {
+                    end += 2;
+                    while end < self.bytes.len() && self.bytes[end].is_ascii_digit() {
+                        end += 1;
+                    }
+                    break;
+                }
+                _ => break,
+            }
+        }
+        let value = std::str::from_utf8(&self.bytes[start..end])
+            .ok()?
+            .parse()
+            .ok()?;
+        self.position = end;
+        Some(value)
+    }
+
+    /// Reads an arc flag, which may be written without a separator to what follows.
+    fn flag(&mut self) -> Option<bool> {
+        let flag = match self.peek()? {
+            b'0' => false,
+            b'1' => true,
+            _ => return None,
+        };
+        self.advance();
+        Some(flag)
+    }
+
+    fn point(&mut self) -> Option<Point2> {
+        Some(Point2::new(self.number()?, self.number()?))
+    }
+
+    fn points<const N: usize>(&mut self) -> Option<[Point2; N]> {
+        let mut points = [Point2::new(0.0, 0.0); N];
+        for point in points.iter_mut() {
+            *point = self.point()?;
+        }
+        Some(points)
+    }
+}
+
+/// An affine 2D transform in SVG's `matrix(a, b, c, d, e, f)` form.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Affine([f64; 6]);
+
+impl Affine {
+    const IDENTITY: Affine = Affine([1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+
+    fn translate(x: f64, y: f64) -> Affine {
+        Affine([1.0, 0.0, 0.0, 1.0, x, y])
+    }
+
+    fn scale(x: f64, y: f64) -> Affine {
+        Affine([x, 0.0, 0.0, y, 0.0, 0.0])
+    }
+
+    fn rotate(degrees: f64) -> Affine {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        Affine([cos, sin, -sin, cos, 0.0, 0.0])
+    }
+
+    /// Returns the transform applying `inner` first and `self` afterwards.
+    fn then(&self, inner: &Affine) -> Affine {
+        let [a, b, c, d, e, f] = self.0;
+        let [ia, ib, ic, id, ie, if_] = inner.0;
+        Affine([
+            a * ia + c * ib,
+            b * ia + d * ib,
+            a * ic + c * id,
+            b * ic + d * id,
+            a * ie + c * if_ + e,
+            b * ie + d * if_ + f,
+        ])
+    }
+
+    /// Applies the transform and mirrors the result into the model's Y-up space.
+    fn apply(&self, point: Point2) -> Point2 {
+        let [a, b, c, d, e, f] = self.0;
+        Point2::new(
+            a * point.x + c * point.y + e,
+            -(b * point.x + d * point.y + f),
+        )
+    }
+
+    fn scale_factor(&self) -> f64 {
+        let [a, b, c, d, _, _] = self.0;
+        (a * d - b * c).abs().sqrt()
+    }
+}
+
+fn parse_transform(transform: &str) -> Result<Affine, SvgImportError> {
+    let invalid = || SvgImportError::InvalidTransform(transform.to_string());
+    let mut result = Affine::IDENTITY;
+    let mut rest = transform.trim();
+
+    while !rest.is_empty() {
+        let open = rest.find('(').ok_or_else(invalid)?;
+        let close = rest.find(')').ok_or_else(invalid)?;
+        if close < open {
+            return Err(invalid());
+        }
+        let name = rest[..open].trim();
+        let mut lexer = Lexer::new(&rest[open + 1..close]);
+        let mut arguments = Vec::new();
+        while let Some(argument) = lexer.number() {
+            arguments.push(argument);
+        }
+        if lexer.peek().is_some() {
+            return Err(invalid());
+        }
+
+        let transform = match (name, arguments.as_slice()) {
+            ("matrix", &[a, b, c, d, e, f]) => Affine([a, b, c, d, e, f]),
+            ("translate", &[x]) => Affine::translate(x, 0.0),
+            ("translate", &[x, y]) => Affine::translate(x, y),
+            ("scale", &[s]) => Affine::scale(s, s),
+            ("scale", &[x, y]) => Affine::scale(x, y),
+            ("rotate", &[degrees]) => Affine::rotate(degrees),
+            ("rotate", &[degrees, x, y]) => Affine::translate(x, y)
+                .then(&Affine::rotate(degrees))
+                .then(&Affine::translate(-x, -y)),
+            ("skewX", &[degrees]) => Affine([1.0, 0.0, degrees.to_radians().tan(), 1.0, 0.0, 0.0]),
+            ("skewY", &[degrees]) => Affine([1.0, degrees.to_radians().tan(), 0.0, 1.0, 0.0, 0.0]),
+            _ => return Err(invalid()),
+        };
+        result = result.then(&transform);
+        rest = rest[close + 1..].trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+    }
+
+    Ok(result)
+}
+
+/// A start or end tag of an XML element.
+struct Tag<'a> {
+    name: &'a str,
+    attributes: Vec<(&'a str, &'a str)>,
+    closing: bool,
+    self_closing: bool,
+}
+
+impl<'a> Tag<'a> {
+    fn attribute(&self, name: &str) -> Option<&'a str> {
+        self.attributes
+            .iter()
+            .find(|(attribute_name, _)| *attribute_name == name)
+            .map(|(_, value)| *value)
+    }
+}
+
+/// A minimal scanner over the tags of an XML document, which skips text, comments,
+/// processing instructions, declarations and character data.
+struct Tags<'a> {
+    document: &'a str,
+    position: usize,
+}
+
+impl<'a> Tags<'a> {
+    fn new(document: &'a str) -> Self {
+        Self {
+            document,
+            position: 0,
+        }
+    }
+
+    fn skip_past(&mut self, terminator: &str) -> Result<(), SvgImportError> {
+        match self.document[self.position..].find(terminator) {
+            Some(offset) => {
+                self.position += offset + terminator.len();
+                Ok(())
+            }
+            None => Err(SvgImportError::MalformedDocument(format!(
+                "missing '{}'",
+                terminator
+            ))),
+        }
+    }
+
+    fn parse_tag(&mut self) -> Result<Tag<'a>, SvgImportError> {
+        let malformed = |message: &str| SvgImportError::MalformedDocument(message.to_string());
+        let document = self.document;
+        let bytes = document.as_bytes();
+
+        // Skip '<'
+        self.position += 1;
+        let closing = bytes.get(self.position) == Some(&b'/');
+        if closing {
+            self.position += 1;
+        }
+        let name_start = self.position;
+        while self.position < bytes.len()
+            && !bytes[self.position].is_ascii_whitespace()
+            && !matches!(bytes[self.position], b'>' | b'/')
+        {
+            self.position += 1;
+        }
+        let name = &document[name_start..self.position];
+        if name.is_empty() {
+            return Err(malformed("empty tag name"));
+        }
+
+        let mut attributes = Vec::new();
+        loop {
+            while self.position < bytes.len() && bytes[self.position].is_ascii_whitespace() {
+                self.position += 1;
+            }
+            match bytes.get(self.position) {
+                None => return Err(malformed("unterminated tag")),
+                Some(b'>') => {
+                    self.position += 1;
+                    return Ok(Tag {
+                        name,
+                        attributes,
+                        closing,
+                        self_closing: false,
+                    });
+                }
+                Some(b'/') if bytes.get(self.position + 1) == Some(&b'>') => {
+                    self.position += 2;
+                    return Ok(Tag {
+                        name,
+                        attributes,
+                        closing,
+                        self_closing: true,
+                    });
+                }
+                Some(_) => {
+                    let equals = document[self.position..]
+                        .find('=')
+                        .ok_or_else(|| malformed("attribute without value"))?;
+                    let attribute_name = document[self.position..self.position + equals].trim();
+                    self.position += equals + 1;
+                    while self.position < bytes.len() && bytes[self.position].is_ascii_whitespace()
+                    {
+                        self.position += 1;
+                    }
+                    let quote = match bytes.get(self.position) {
+                        Some(quote @ (b'"' | b'\'')) => *quote as char,
+                        _ => return Err(malformed("unquoted attribute value")),
+                    };
+                    self.position += 1;
+                    let length = document[self.position..]
+                        .find(quote)
+                        .ok_or_else(|| malformed("unterminated attribute value"))?;
+                    attributes.push((
+                        attribute_name,
+                        &document[self.position..self.position + length],
+                    ));
+                    self.position += length + 1;
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for Tags<'a> {
+    type Item = Result<Tag<'a>, SvgImportError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let offset = self.document[self.position..].find('<')?;
+            self.position += offset;
+            let rest = &self.document[self.position..];
+
+            let skipped = if rest.starts_with("<!--") {
+                self.skip_past("-->")
+            } else if rest.starts_with("<![CDATA[") {
+                self.skip_past("]]>")
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")
+            } else if rest.starts_with("<!") {
+                self.skip_past(">")
+            } else {
+                return Some(self.parse_tag());
+            };
+            if let Err(error) = skipped {
+                return Some(Err(error));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_transform_list() {
+        let transform = parse_transform("translate(10, 20) scale(2)").unwrap();
+        assert_eq!(transform, Affine([2.0, 0.0, 0.0, 2.0, 10.0, 20.0]));
+
+        let transform = parse_transform("rotate(90 1 1)").unwrap();
+        let point = transform.apply(Point2::new(2.0, 1.0));
+        assert!((point.x - 1.0).abs() < 1e-12 && (point.y + 2.0).abs() < 1e-12);
+
+        assert!(parse_transform("wobble(1)").is_err());
+    }
+
+    #[test]
+    fn test_lexer_compact_numbers_and_flags() {
+        let mut lexer = Lexer::new("-1.5.5e1-2 11");
+        assert_eq!(lexer.number(), Some(-1.5));
+        assert_eq!(lexer.number(), Some(5.0));
+        assert_eq!(lexer.number(), Some(-2.0));
+        assert_eq!(lexer.flag(), Some(true));
+        assert_eq!(lexer.flag(), Some(true));
+        assert_eq!(lexer.number(), None);
+    }
+}
@@ -0,0 +1,11 @@
+//! Conversion between 2D geometry and SVG documents.
+//!
+//! The model's Y axis points up while SVG's points down, so coordinates are mirrored
+//! on the X axis in both directions. A contour exported with [`polygons_to_svg`] is
+//! imported again by [`polygons_from_svg`] at its original position.
+
+mod export;
+mod import;
+
+pub use export::*;
+pub use import::*;
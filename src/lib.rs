@@ -6,11 +6,18 @@ mod error;
 mod fill_rule;
 mod manifold_vec;
 mod mesh_gl;
+mod mesh_gl64;
 mod polygons;
 mod quality;
 mod simple_polygon;
 
+pub mod interference;
+pub mod io;
 pub mod manifold;
+pub mod orientation;
+pub mod packing;
+#[cfg(feature = "text")]
+pub mod text;
 pub mod types;
 
 pub type HalfEdgeIndex = usize;
@@ -25,6 +32,7 @@ pub use manifold::Manifold;
 #[doc(inline)]
 pub use manifold3d_sys as sys;
 pub use mesh_gl::*;
+pub use mesh_gl64::*;
 pub use polygons::*;
 pub use quality::*;
 pub use simple_polygon::*;
@@ -34,6 +42,8 @@ pub mod macros {
         #[doc(inline)]
         pub use manifold3d_macros::manifold_manage_vertex_properties as manage_vertex_properties;
         #[doc(inline)]
+        pub use manifold3d_macros::manifold_sdf as sdf;
+        #[doc(inline)]
         pub use manifold3d_macros::manifold_warp as warp;
     }
 }
@@ -1,22 +1,24 @@
 use crate::bounding_box::BoundingBox;
 use crate::error::{check_error, Error};
 use crate::mesh_gl::MeshGL;
+use crate::mesh_gl64::MeshGL64;
 use manifold3d_sys::{
     manifold_alloc_box, manifold_alloc_manifold, manifold_alloc_manifold_vec,
-    manifold_alloc_meshgl, manifold_as_original, manifold_batch_boolean, manifold_batch_hull,
-    manifold_boolean, manifold_bounding_box, manifold_calculate_curvature,
-    manifold_calculate_normals, manifold_copy, manifold_cube, manifold_cylinder,
-    manifold_decompose, manifold_delete_manifold, manifold_difference, manifold_empty,
-    manifold_epsilon, manifold_genus, manifold_get_circular_segments, manifold_get_meshgl,
-    manifold_hull, manifold_hull_pts, manifold_intersection, manifold_is_empty,
-    manifold_manifold_vec, manifold_manifold_vec_set, manifold_min_gap, manifold_mirror,
-    manifold_num_edge, manifold_num_prop, manifold_num_tri, manifold_num_vert, manifold_of_meshgl,
-    manifold_original_id, manifold_project, manifold_refine, manifold_refine_to_length,
-    manifold_refine_to_tolerance, manifold_scale, manifold_set_properties, manifold_slice,
-    manifold_smooth_by_normals, manifold_smooth_out, manifold_sphere, manifold_split,
-    manifold_split_by_plane, manifold_status, manifold_surface_area, manifold_tetrahedron,
-    manifold_transform, manifold_translate, manifold_trim_by_plane, manifold_union,
-    manifold_volume, manifold_warp, ManifoldManifold, ManifoldOpType, ManifoldVec3,
+    manifold_alloc_meshgl, manifold_alloc_meshgl64, manifold_alloc_polygons, manifold_as_original,
+    manifold_batch_boolean, manifold_batch_hull, manifold_boolean, manifold_bounding_box,
+    manifold_calculate_curvature, manifold_calculate_normals, manifold_copy, manifold_cube,
+    manifold_cylinder, manifold_decompose, manifold_delete_manifold, manifold_difference,
+    manifold_empty, manifold_epsilon, manifold_genus, manifold_get_circular_segments,
+    manifold_get_meshgl, manifold_get_meshgl64, manifold_hull, manifold_hull_pts,
+    manifold_intersection, manifold_is_empty, manifold_manifold_vec, manifold_manifold_vec_set,
+    manifold_min_gap, manifold_mirror, manifold_num_edge, manifold_num_prop, manifold_num_tri,
+    manifold_num_vert, manifold_of_meshgl, manifold_of_meshgl64, manifold_original_id,
+    manifold_project, manifold_refine, manifold_refine_to_length, manifold_refine_to_tolerance,
+    manifold_scale, manifold_set_properties, manifold_slice, manifold_smooth_by_normals,
+    manifold_smooth_out, manifold_sphere, manifold_split, manifold_split_by_plane, manifold_status,
+    manifold_surface_area, manifold_tetrahedron, manifold_transform, manifold_translate,
+    manifold_trim_by_plane, manifold_union, manifold_volume, manifold_warp, ManifoldManifold,
+    ManifoldOpType, ManifoldVec3,
 };
 use std::mem::transmute;
 use std::os::raw::{c_int, c_void};
@@ -31,9 +33,40 @@ use crate::types::{
 pub use crate::macros::manifold::*;
 use crate::manifold_vec::ManifoldVec;
 use crate::{HalfEdgeIndex, ManifoldErrorExt, Polygons};
+pub use blend::*;
+pub use comparison::*;
+pub use hollow::*;
+pub use level_set::*;
+pub use mass_properties::*;
+pub use offset::*;
+pub use overhang::*;
 pub use properties::*;
+pub use query::*;
+pub use ray_cast::*;
+pub use simplify::*;
+pub use snapshot::*;
+pub use sweep::*;
+pub use wall_thickness::*;
 pub use warp::*;
 
+mod blend;
+mod comparison;
+pub(crate) mod geometry;
+mod hollow;
+mod level_set;
+mod mass_properties;
+mod minkowski;
+mod offset;
+mod overhang;
+mod primitives;
+mod query;
+mod ray_cast;
+mod simplify;
+mod snapshot;
+mod surface_index;
+mod sweep;
+mod wall_thickness;
+
 /// Represents a manifold.
 pub struct Manifold(*mut ManifoldManifold);
 
@@ -64,7 +97,7 @@ impl Manifold {
     pub fn new_tetrahedron() -> Manifold {
         let manifold_ptr = unsafe { manifold_alloc_manifold() };
         unsafe { manifold_tetrahedron(manifold_ptr as *mut c_void) };
-        Manifold(manifold_ptr)
+        Manifold::from_ptr(manifold_ptr)
     }
 
     /// Constructs a 3D cuboid with the specified dimensions in the first octant of 3D space.
@@ -153,7 +186,7 @@ impl Manifold {
             )
         };
 
-        Manifold(manifold_ptr)
+        Manifold::from_ptr(manifold_ptr)
     }
 
     /// Constructs a 3D cuboid with the specified dimensions in the first octant of 3D space.
@@ -404,6 +437,21 @@ impl Manifold {
         Manifold::try_from(mesh_gl)
     }
 
+    /// Constructs a manifold object from a double precision [`MeshGL64`] representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `mesh_gl`: A reference to a [`MeshGL64`] object, which represents the mesh geometry.
+    ///
+    /// # Returns
+    ///
+    /// A new manifold object representing the 3D manifold created from the
+    /// provided [`MeshGL64`]. In case of failure, an [`enum@Error`] is returned encapsulating the reason
+    /// for failure.
+    pub fn from_mesh_gl64(mesh_gl: &MeshGL64) -> Result<Manifold, Error> {
+        Manifold::try_from(mesh_gl)
+    }
+
     /// Constructs a smooth version of the input [`MeshGL`] mesh by creating tangents.
     ///
     /// The actual triangle resolution remains unchanged; use [`Manifold::refine_via_edge_splits`]
@@ -904,7 +952,7 @@ impl Manifold {
     pub fn slice_by_height(&self, height: impl Into<PositiveF64>) -> Polygons {
         let polygons_ptr = unsafe {
             manifold_slice(
-                manifold_alloc_manifold() as *mut c_void,
+                manifold_alloc_polygons() as *mut c_void,
                 self.0,
                 height.into().into(),
             )
@@ -940,7 +988,7 @@ impl Manifold {
     /// ```
     pub fn project(&self) -> Polygons {
         let polygons_ptr =
-            unsafe { manifold_project(manifold_alloc_manifold() as *mut c_void, self.0) };
+            unsafe { manifold_project(manifold_alloc_polygons() as *mut c_void, self.0) };
         Polygons::from_ptr(polygons_ptr)
     }
 
@@ -2111,6 +2159,16 @@ impl Manifold {
             unsafe { manifold_get_meshgl(manifold_alloc_meshgl() as *mut c_void, self.0) };
         MeshGL::from_ptr(mesh_gl_ptr)
     }
+
+    /// Returns a double precision [MeshGL64] representation of the manifold.
+    ///
+    /// Unlike [`Manifold::as_mesh`], the vertex properties keep the full precision of the
+    /// manifold.
+    pub fn as_mesh64(&self) -> MeshGL64 {
+        let mesh_gl_ptr =
+            unsafe { manifold_get_meshgl64(manifold_alloc_meshgl64() as *mut c_void, self.0) };
+        MeshGL64::from_ptr(mesh_gl_ptr)
+    }
 }
 
 impl TryFrom<&'_ MeshGL> for Manifold {
@@ -2123,6 +2181,16 @@ impl TryFrom<&'_ MeshGL> for Manifold {
     }
 }
 
+impl TryFrom<&'_ MeshGL64> for Manifold {
+    type Error = Error;
+
+    fn try_from(value: &'_ MeshGL64) -> Result<Self, Self::Error> {
+        let manifold_ptr =
+            unsafe { manifold_of_meshgl64(manifold_alloc_manifold() as *mut c_void, value.ptr()) };
+        check_error(Manifold::from_ptr(manifold_ptr))
+    }
+}
+
 impl Clone for Manifold {
     fn clone(&self) -> Self {
         let manifold_ptr =
@@ -2140,6 +2208,8 @@ impl Drop for Manifold {
 }
 
 /// Represents a boolean operation that can be performed on a [Manifold].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BooleanOperation {
     /// Represents a union or addition operation.
     Add,
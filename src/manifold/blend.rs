@@ -0,0 +1,270 @@
+use crate::bounding_box::BoundingBox;
+use crate::manifold::geometry::{
+    add, cross, dihedral_edges, dot, length, scale, sub, TriangleMesh, Vector,
+};
+use crate::manifold::{BooleanOperation, Manifold};
+use crate::types::{Matrix4x3, NormalizedAngle, Point3, PositiveF64, PositiveI32, Vec3};
+
+/// The dihedral angle in degrees below which an edge is flat and never blended.
+const MIN_BLEND_ANGLE: f64 = 1e-3;
+
+/// Selects the edges blended by [`Manifold::fillet`] and [`Manifold::chamfer`]. An edge is
+/// selected if it passes all given filters. The default selects every edge with a dihedral
+/// angle of at least 30°, like [`CreaseOptions`](crate::CreaseOptions), so the facets of
+/// curved surfaces are not blended.
+pub struct EdgeSelection {
+    /// Only edges between the faces of one of these pairs of face IDs, in either order. On a
+    /// manifold without face IDs, this selects no edges.
+    pub face_id_pairs: Option<Vec<(u64, u64)>>,
+    /// Only edges whose dihedral angle, i.e. the angle between the normals of their two
+    /// triangles, lies within this inclusive range: 90° for the edges of a cube.
+    pub angle_range: Option<(NormalizedAngle, NormalizedAngle)>,
+    /// Only edges lying completely inside this bounding box.
+    pub region: Option<BoundingBox>,
+}
+
+impl Default for EdgeSelection {
+    /// Edges from 30° on, regardless of face IDs and region.
+    fn default() -> Self {
+        Self {
+            face_id_pairs: None,
+            angle_range: Some((
+                NormalizedAngle::from_degrees(30.0),
+                NormalizedAngle::from_degrees(180.0),
+            )),
+            region: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Profile {
+    Fillet,
+    Chamfer,
+}
+
+impl Manifold {
+    /// Rounds the selected edges with the radius.
+    ///
+    /// Every selected edge of the mesh is blended on its own by a straight piece of the
+    /// profile along it: convex edges are cut away, concave edges are filled up to a
+    /// cylinder of the radius which touches both faces. The cylinder is a polygon with the
+    /// number of segments given by the quality settings, see
+    /// [`crate::set_circular_segments`]. The blends are combined with the manifold by
+    /// [`Manifold::batch_boolean`], so the result stays manifold. Where selected edges meet
+    /// at a vertex, their blends overlap instead of forming a spherical corner.
+    /// The radius should be small compared to the faces next to the edges, otherwise the
+    /// blends cut through or fill over neighboring geometry.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::manifold::EdgeSelection;
+    /// use manifold3d::types::{Point3, PositiveF64};
+    /// use manifold3d::Manifold;
+    ///
+    /// let cube = Manifold::new_cuboid(
+    ///     PositiveF64::new(10.0).unwrap(),
+    ///     PositiveF64::new(10.0).unwrap(),
+    ///     PositiveF64::new(10.0).unwrap(),
+    ///     true,
+    /// );
+    ///
+    /// let rounded = cube.fillet(&EdgeSelection::default(), PositiveF64::new(1.0).unwrap());
+    /// assert!((rounded.bounding_box().dimensions().x - 10.0).abs() < 1e-9);
+    /// assert!(!rounded.contains_point(Point3::new(4.9, 4.9, 0.0)));
+    /// assert!(rounded.contains_point(Point3::new(4.5, 4.5, 0.0)));
+    /// ```
+    pub fn fillet(&self, edges: &EdgeSelection, radius: PositiveF64) -> Manifold {
+        self.blend(edges, radius.get(), Profile::Fillet)
+    }
+
+    /// Bevels the selected edges, setting the flat bevel back from the edge by the distance
+    /// along both faces.
+    ///
+    /// Like in [`Manifold::fillet`], every selected edge is blended on its own: convex edges
+    /// are cut away and concave edges are filled up to the bevel.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::manifold::EdgeSelection;
+    /// use manifold3d::types::{NormalizedAngle, PositiveF64};
+    /// use manifold3d::Manifold;
+    ///
+    /// let cube = Manifold::new_cuboid(
+    ///     PositiveF64::new(10.0).unwrap(),
+    ///     PositiveF64::new(10.0).unwrap(),
+    ///     PositiveF64::new(10.0).unwrap(),
+    ///     true,
+    /// );
+    /// let square_edges = EdgeSelection {
+    ///     angle_range: Some((
+    ///         NormalizedAngle::from_degrees(89.0),
+    ///         NormalizedAngle::from_degrees(91.0),
+    ///     )),
+    ///     ..EdgeSelection::default()
+    /// };
+    ///
+    /// let beveled = cube.chamfer(&square_edges, PositiveF64::new(1.0).unwrap());
+    /// // Every edge loses a prism of half a unit square in section, and at every corner
+    /// // the prisms of its three edges overlap by 0.75
+    /// assert!((beveled.volume() - (1000.0 - 12.0 * 5.0 + 8.0 * 0.75)).abs() < 1e-6);
+    /// ```
+    pub fn chamfer(&self, edges: &EdgeSelection, distance: PositiveF64) -> Manifold {
+        self.blend(edges, distance.get(), Profile::Chamfer)
+    }
+
+    fn blend(&self, edges: &EdgeSelection, size: f64, profile: Profile) -> Manifold {
+        let mesh_gl = self.as_mesh64();
+        let mesh = TriangleMesh::of_mesh_gl(&mesh_gl);
+        let face_ids = mesh_gl.face_ids();
+
+        let mut removed = Vec::new();
+        let mut added = Vec::new();
+        for dihedral in dihedral_edges(&mesh.positions, &mesh.triangles) {
+            let (triangle, pair_triangle) = (dihedral.halfedge / 3, dihedral.pair / 3);
+            if let Some(pairs) = &edges.face_id_pairs {
+                if face_ids.is_empty() {
+                    continue;
+                }
+                let (a, b) = (face_ids[triangle], face_ids[pair_triangle]);
+                if !pairs.contains(&(a, b)) && !pairs.contains(&(b, a)) {
+                    continue;
+                }
+            }
+            let corner = |halfedge: usize, offset: usize| {
+                mesh.positions[mesh.triangles[halfedge / 3][(halfedge + offset) % 3]]
+            };
+            let edge = Edge {
+                start: corner(dihedral.halfedge, 0),
+                end: corner(dihedral.halfedge, 1),
+                opposite: corner(dihedral.halfedge, 2),
+                pair_opposite: corner(dihedral.pair, 2),
+                normal: dihedral.normal,
+                pair_normal: dihedral.pair_normal,
+                angle: dihedral.angle,
+            };
+            if let Some(region) = &edges.region {
+                let inside = |[x, y, z]: Vector| region.contains_point(Point3::new(x, y, z));
+                if !inside(edge.start) || !inside(edge.end) {
+                    continue;
+                }
+            }
+            let Some((blend, is_convex)) = edge.blend(edges, size, profile) else {
+                continue;
+            };
+            if is_convex {
+                removed.push(blend);
+            } else {
+                added.push(blend);
+            }
+        }
+
+        let cut = self.batch_boolean(&removed, BooleanOperation::Subtract);
+        cut.batch_boolean(&added, BooleanOperation::Add)
+    }
+}
+
+/// An edge of the mesh with the vertices opposite of it and the normals of its two
+/// triangles.
+struct Edge {
+    start: Vector,
+    end: Vector,
+    opposite: Vector,
+    pair_opposite: Vector,
+    normal: Vector,
+    pair_normal: Vector,
+    /// The dihedral angle in degrees.
+    angle: f64,
+}
+
+impl Edge {
+    /// Returns the solid to cut away from or add to the manifold along the edge and whether
+    /// the edge is convex, or `None` if the edge is degenerate or not selected by its angle.
+    fn blend(
+        &self,
+        edges: &EdgeSelection,
+        size: f64,
+        profile: Profile,
+    ) -> Option<(Manifold, bool)> {
+        let along = sub(self.end, self.start);
+        let edge_length = length(along);
+        if edge_length == 0.0 {
+            return None;
+        }
+        let direction = scale(along, 1.0 / edge_length);
+        let (normal, pair_normal, angle) = (self.normal, self.pair_normal, self.angle);
+        if angle < MIN_BLEND_ANGLE {
+            return None;
+        }
+        if let Some((min, max)) = edges.angle_range {
+            if angle < min.get() || angle > max.get() {
+                return None;
+            }
+        }
+
+        // The directions in both faces pointing away from the edge
+        let into_face = |normal: Vector, opposite: Vector| {
+            let inward = cross(normal, direction);
+            let inward = scale(inward, 1.0 / length(inward));
+            if dot(inward, sub(opposite, self.start)) < 0.0 {
+                scale(inward, -1.0)
+            } else {
+                inward
+            }
+        };
+        let face = into_face(normal, self.opposite);
+        let pair_face = into_face(pair_normal, self.pair_opposite);
+        let is_convex = dot(normal, pair_face) < 0.0;
+        // Blends of convex edges reach out of the manifold, those of concave edges into it,
+        // so they do not share faces with the manifold
+        let side = if is_convex { 1.0 } else { -1.0 };
+        let overshoot = side * size / 2.0;
+
+        let setback = match profile {
+            Profile::Fillet => size * (angle.to_radians() / 2.0).tan(),
+            Profile::Chamfer => size,
+        };
+        let tangent = scale(face, setback);
+        let pair_tangent = scale(pair_face, setback);
+        let mut outline = vec![
+            tangent,
+            pair_tangent,
+            add(tangent, scale(normal, overshoot)),
+            add(pair_tangent, scale(pair_normal, overshoot)),
+            scale(add(normal, pair_normal), overshoot),
+        ];
+        let center = sub(tangent, scale(normal, side * size));
+        if profile == Profile::Fillet {
+            outline.push(center);
+        }
+        let points = outline
+            .iter()
+            .flat_map(|&offset| [add(self.start, offset), add(self.end, offset)])
+            .map(|[x, y, z]| Vec3::new(x, y, z))
+            .collect::<Vec<_>>();
+        let prism = Manifold::convex_hull_from_points(&points);
+        if profile == Profile::Chamfer {
+            return Some((prism, is_convex));
+        }
+
+        let margin = size;
+        // Start the polygon of the cylinder at the tangent point on the first face
+        let u = scale(normal, side);
+        let v = cross(direction, u);
+        let origin = sub(add(self.start, center), scale(direction, margin));
+        let cylinder = Manifold::new_cylinder(
+            PositiveF64::new(edge_length + 2.0 * margin).unwrap(),
+            PositiveF64::new(size).unwrap(),
+            None::<PositiveF64>,
+            None::<PositiveI32>,
+            false,
+        )
+        .transform(Matrix4x3::new([
+            Vec3::new(u[0], u[1], u[2]),
+            Vec3::new(v[0], v[1], v[2]),
+            Vec3::new(direction[0], direction[1], direction[2]),
+            Vec3::new(origin[0], origin[1], origin[2]),
+        ]));
+        Some((prism.difference(&cylinder), is_convex))
+    }
+}
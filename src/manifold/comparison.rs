@@ -0,0 +1,310 @@
+use crate::manifold::geometry::{add, cross, length, scale, sub, Vector};
+use crate::manifold::surface_index::SurfaceIndex;
+use crate::manifold::Manifold;
+use crate::types::{Point3, PositiveF64};
+use std::fmt;
+
+/// The number of surface samples per manifold which [`Manifold::approx_difference`] aims for.
+const COMPARISON_SAMPLE_COUNT: f64 = 4096.0;
+
+/// The first property in which two manifolds differ by more than the tolerance, as returned by
+/// [`Manifold::approx_difference`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShapeDifference {
+    Genus {
+        left: i32,
+        right: i32,
+    },
+    /// The volumes differ by more than the tolerance times the larger surface area.
+    Volume {
+        left: f64,
+        right: f64,
+    },
+    /// A corner of the bounding boxes moved by more than the tolerance along an axis.
+    BoundingBox {
+        left_min: Point3,
+        left_max: Point3,
+        right_min: Point3,
+        right_max: Point3,
+    },
+    /// The surfaces deviate by more than the tolerance.
+    Surface {
+        /// The sampled Hausdorff distance between both surfaces.
+        distance: f64,
+        /// The sample point furthest from the other surface.
+        position: Point3,
+        /// Whether the sample point lies on the left manifold.
+        is_on_left: bool,
+    },
+}
+
+impl fmt::Display for ShapeDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShapeDifference::Genus { left, right } => {
+                write!(f, "genus differs: left {left}, right {right}")
+            }
+            ShapeDifference::Volume { left, right } => {
+                write!(f, "volume differs: left {left}, right {right}")
+            }
+            ShapeDifference::BoundingBox {
+                left_min,
+                left_max,
+                right_min,
+                right_max,
+            } => write!(
+                f,
+                "bounding box differs: left {left_min:?} to {left_max:?}, right {right_min:?} to {right_max:?}"
+            ),
+            ShapeDifference::Surface {
+                distance,
+                position,
+                is_on_left,
+            } => write!(
+                f,
+                "surfaces deviate by {distance} at {position:?} on the {} manifold",
+                if *is_on_left { "left" } else { "right" }
+            ),
+        }
+    }
+}
+
+impl Manifold {
+    /// Approximates the symmetric Hausdorff distance between the surfaces of this manifold and
+    /// another one, the largest distance from a point on either surface to the other surface.
+    ///
+    /// Both surfaces are sampled on a regular grid per triangle, including all vertices, with
+    /// about `sample_density` samples per unit area. The result underestimates the true
+    /// distance by at most the sample spacing.
+    ///
+    /// # Returns
+    ///
+    /// Zero if both manifolds are empty, infinity if only one of them is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::{PositiveF64, Vec3};
+    /// use manifold3d::Manifold;
+    ///
+    /// let cube = Manifold::new_cuboid(
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     false,
+    /// );
+    /// let moved = cube.translate(Vec3::new(0.0, 0.0, 0.25));
+    ///
+    /// let distance = cube.hausdorff_distance(&moved, PositiveF64::new(100.0).unwrap());
+    /// assert!((distance - 0.25).abs() < 1e-9);
+    /// ```
+    pub fn hausdorff_distance(&self, other: &Manifold, sample_density: PositiveF64) -> f64 {
+        let (index, other_index) = (self.surface_index(), other.surface_index());
+        [(&index, &other_index), (&other_index, &index)]
+            .into_iter()
+            .filter_map(|(from, to)| furthest_sample(from, to, sample_density.get()))
+            .fold(0.0, |furthest, (distance, _)| furthest.max(distance))
+    }
+
+    /// Approximates the one-sided Hausdorff distance from the surface of this manifold to the
+    /// surface of another one, the largest distance from a point on this surface to the other
+    /// surface. It is zero if this surface lies on the other one, e.g. for a patch of it.
+    ///
+    /// The surface is sampled like for [`Manifold::hausdorff_distance`].
+    pub fn one_sided_hausdorff_distance(
+        &self,
+        other: &Manifold,
+        sample_density: PositiveF64,
+    ) -> f64 {
+        furthest_sample(
+            &self.surface_index(),
+            &other.surface_index(),
+            sample_density.get(),
+        )
+        .map_or(0.0, |(distance, _)| distance)
+    }
+
+    /// Checks whether two manifolds are equal up to the tolerance, see
+    /// [`Manifold::approx_difference`].
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::{PositiveF64, PositiveI32};
+    /// use manifold3d::Manifold;
+    ///
+    /// let sphere = Manifold::new_sphere(
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     Some(PositiveI32::new(64).unwrap()),
+    /// );
+    /// let coarse_sphere = Manifold::new_sphere(
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     Some(PositiveI32::new(60).unwrap()),
+    /// );
+    ///
+    /// assert!(sphere.approx_eq(&coarse_sphere, PositiveF64::new(0.01).unwrap()));
+    /// assert!(!sphere.approx_eq(&coarse_sphere, PositiveF64::new(0.0001).unwrap()));
+    /// ```
+    pub fn approx_eq(&self, other: &Manifold, tolerance: PositiveF64) -> bool {
+        self.approx_difference(other, tolerance).is_none()
+    }
+
+    /// Compares two manifolds up to the tolerance, e.g. for regression tests of modeling
+    /// pipelines.
+    ///
+    /// The genus has to match exactly. The volumes may differ by the tolerance times the
+    /// larger surface area, the corners of the bounding boxes by the tolerance along every
+    /// axis and the surfaces by the tolerance in the sampled
+    /// [`Manifold::hausdorff_distance`], using about 4096 samples per manifold.
+    ///
+    /// # Returns
+    ///
+    /// The first difference exceeding the tolerance, checked in the order above, or `None` if
+    /// the manifolds are approximately equal.
+    pub fn approx_difference(
+        &self,
+        other: &Manifold,
+        tolerance: PositiveF64,
+    ) -> Option<ShapeDifference> {
+        let tolerance = tolerance.get();
+        if self.is_empty() || other.is_empty() {
+            return match (self.is_empty(), other.is_empty()) {
+                (true, true) => None,
+                _ => Some(ShapeDifference::Volume {
+                    left: self.volume(),
+                    right: other.volume(),
+                }),
+            };
+        }
+
+        let (left, right) = (self.genus(), other.genus());
+        if left != right {
+            return Some(ShapeDifference::Genus { left, right });
+        }
+
+        let (left, right) = (self.volume(), other.volume());
+        let area = self.surface_area().max(other.surface_area());
+        if (left - right).abs() > tolerance * area {
+            return Some(ShapeDifference::Volume { left, right });
+        }
+
+        let (left_box, right_box) = (self.bounding_box(), other.bounding_box());
+        let (left_min, left_max) = (left_box.min_point(), left_box.max_point());
+        let (right_min, right_max) = (right_box.min_point(), right_box.max_point());
+        let deviation = [left_min - right_min, left_max - right_max]
+            .iter()
+            .map(|delta| delta.x.abs().max(delta.y.abs()).max(delta.z.abs()))
+            .fold(0.0, f64::max);
+        if deviation > tolerance {
+            return Some(ShapeDifference::BoundingBox {
+                left_min,
+                left_max,
+                right_min,
+                right_max,
+            });
+        }
+
+        let sample_density = COMPARISON_SAMPLE_COUNT / area;
+        let (index, other_index) = (self.surface_index(), other.surface_index());
+        let on_left = furthest_sample(&index, &other_index, sample_density);
+        let on_right = furthest_sample(&other_index, &index, sample_density);
+        let ((distance, position), is_on_left) = match (on_left, on_right) {
+            (Some(left), Some(right)) if right.0 > left.0 => (right, false),
+            (Some(left), _) => (left, true),
+            (None, Some(right)) => (right, false),
+            (None, None) => return None,
+        };
+        (distance > tolerance).then_some(ShapeDifference::Surface {
+            distance,
+            position,
+            is_on_left,
+        })
+    }
+}
+
+/// Finds the sample point of the surface furthest from the other surface and its distance,
+/// or `None` if the surface is empty.
+fn furthest_sample(
+    index: &SurfaceIndex,
+    other_index: &SurfaceIndex,
+    sample_density: f64,
+) -> Option<(f64, Point3)> {
+    let mesh = &index.mesh;
+    let mut furthest: Option<(f64, Vector)> = None;
+    for triangle in 0..mesh.triangles.len() {
+        let [a, b, c] = mesh.corners(triangle);
+        let (ab, ac) = (sub(b, a), sub(c, a));
+        let area = length(cross(ab, ac)) / 2.0;
+        let divisions = ((area * sample_density).sqrt().ceil() as usize).clamp(1, 1024);
+        for i in 0..=divisions {
+            for j in 0..=divisions - i {
+                let sample = add(
+                    a,
+                    add(
+                        scale(ab, i as f64 / divisions as f64),
+                        scale(ac, j as f64 / divisions as f64),
+                    ),
+                );
+                let distance = other_index
+                    .closest_point(sample)
+                    .map_or(f64::INFINITY, |closest| closest.distance_squared.sqrt());
+                if furthest.is_none_or(|(furthest, _)| distance > furthest) {
+                    furthest = Some((distance, sample));
+                }
+            }
+        }
+    }
+    furthest.map(|(distance, [x, y, z])| (distance, Point3::new(x, y, z)))
+}
+
+/// Asserts that two manifolds are equal up to the tolerance, see
+/// [`Manifold::approx_difference`](crate::Manifold::approx_difference). On failure, the panic
+/// message describes where the manifolds differ.
+///
+/// # Examples
+/// ```
+/// use manifold3d::types::PositiveF64;
+/// use manifold3d::{assert_manifold_approx_eq, Manifold};
+///
+/// let cube = Manifold::new_cuboid(
+///     PositiveF64::new(1.0).unwrap(),
+///     PositiveF64::new(1.0).unwrap(),
+///     PositiveF64::new(1.0).unwrap(),
+///     true,
+/// );
+/// assert_manifold_approx_eq!(cube, cube.clone(), PositiveF64::new(1e-6).unwrap());
+/// ```
+#[macro_export]
+macro_rules! assert_manifold_approx_eq {
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        if let Some(difference) = $crate::Manifold::approx_difference(&$left, &$right, $tolerance) {
+            panic!("assertion `left ≈ right` failed: {}", difference);
+        }
+    };
+    ($left:expr, $right:expr, $tolerance:expr, $($message:tt)+) => {
+        if let Some(difference) = $crate::Manifold::approx_difference(&$left, &$right, $tolerance) {
+            panic!(
+                "assertion `left ≈ right` failed: {}: {}",
+                format_args!($($message)+),
+                difference
+            );
+        }
+    };
+}
+
+/// Asserts that two manifolds differ by more than the tolerance, see
+/// [`Manifold::approx_difference`](crate::Manifold::approx_difference).
+#[macro_export]
+macro_rules! assert_manifold_approx_ne {
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        if $crate::Manifold::approx_eq(&$left, &$right, $tolerance) {
+            panic!("assertion `left ≉ right` failed: the manifolds are approximately equal");
+        }
+    };
+    ($left:expr, $right:expr, $tolerance:expr, $($message:tt)+) => {
+        if $crate::Manifold::approx_eq(&$left, &$right, $tolerance) {
+            panic!(
+                "assertion `left ≉ right` failed: {}: the manifolds are approximately equal",
+                format_args!($($message)+)
+            );
+        }
+    };
+}
@@ -0,0 +1,185 @@
+//! Vector math and mesh access shared by the analyses implemented on top of [`MeshGL64`].
+
+use crate::manifold::Manifold;
+use crate::mesh_gl64::MeshGL64;
+use std::collections::HashMap;
+
+pub(crate) type Vector = [f64; 3];
+
+pub(crate) fn add(a: Vector, b: Vector) -> Vector {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+pub(crate) fn sub(a: Vector, b: Vector) -> Vector {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+pub(crate) fn scale(a: Vector, factor: f64) -> Vector {
+    [a[0] * factor, a[1] * factor, a[2] * factor]
+}
+
+pub(crate) fn dot(a: Vector, b: Vector) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+pub(crate) fn cross(a: Vector, b: Vector) -> Vector {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+pub(crate) fn length(a: Vector) -> f64 {
+    dot(a, a).sqrt()
+}
+
+/// Returns the vector scaled to unit length, or `None` for the zero vector.
+pub(crate) fn unit(a: Vector) -> Option<Vector> {
+    let a_length = length(a);
+    (a_length > 0.0).then(|| scale(a, 1.0 / a_length))
+}
+
+/// Returns two unit vectors which complete the unit vector `normal` to a right-handed
+/// orthonormal frame. For the Z axis they are the X and Y axes.
+pub(crate) fn orthonormal_basis(normal: Vector) -> (Vector, Vector) {
+    let helper = if normal[1].abs() < 0.9 {
+        cross([0.0, 1.0, 0.0], normal)
+    } else {
+        cross(normal, [1.0, 0.0, 0.0])
+    };
+    let u = scale(helper, 1.0 / length(helper));
+    (u, cross(normal, u))
+}
+
+/// Maps every vertex of a [`MeshGL64`] or [`MeshGL`](crate::MeshGL) to the first vertex
+/// sharing its position, using the merge vectors.
+pub(crate) fn merged_vertices<I: Into<u64>>(
+    vertex_count: usize,
+    merge_from: Vec<I>,
+    merge_to: Vec<I>,
+) -> Vec<usize> {
+    let mut merged = (0..vertex_count).collect::<Vec<_>>();
+    for (from, to) in merge_from.into_iter().zip(merge_to) {
+        merged[from.into() as usize] = to.into() as usize;
+    }
+    merged
+}
+
+/// An edge shared by two triangles, as found by [`dihedral_edges`].
+pub(crate) struct DihedralEdge {
+    /// The halfedge running from the smaller to the larger vertex index, as
+    /// 3 * triangle index + 0, 1, 2.
+    pub(crate) halfedge: usize,
+    /// The halfedge running the other way in the neighboring triangle.
+    pub(crate) pair: usize,
+    /// The unit normal of the triangle of the halfedge.
+    pub(crate) normal: Vector,
+    /// The unit normal of the triangle of the pair.
+    pub(crate) pair_normal: Vector,
+    /// The angle between both normals in degrees, 0° for flat edges and 90° for the edges
+    /// of a cube.
+    pub(crate) angle: f64,
+}
+
+/// Finds every edge whose halfedge has a partner running the other way between the same
+/// vertices, ordered by halfedge. Vertices sharing a position must share their index, see
+/// [`merged_vertices`]. Edges of degenerate triangles are skipped.
+pub(crate) fn dihedral_edges(positions: &[Vector], triangles: &[[usize; 3]]) -> Vec<DihedralEdge> {
+    let normals = triangles
+        .iter()
+        .map(|&[a, b, c]| {
+            let a = positions[a];
+            unit(cross(sub(positions[b], a), sub(positions[c], a)))
+        })
+        .collect::<Vec<_>>();
+    let halfedges = triangles
+        .iter()
+        .enumerate()
+        .flat_map(|(triangle, vertices)| {
+            (0..3).map(move |k| ((vertices[k], vertices[(k + 1) % 3]), 3 * triangle + k))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut edges = Vec::new();
+    for (triangle, vertices) in triangles.iter().enumerate() {
+        for k in 0..3 {
+            let (start, end) = (vertices[k], vertices[(k + 1) % 3]);
+            if start >= end {
+                continue;
+            }
+            let Some(&pair) = halfedges.get(&(end, start)) else {
+                continue;
+            };
+            let (Some(normal), Some(pair_normal)) = (normals[triangle], normals[pair / 3]) else {
+                continue;
+            };
+            edges.push(DihedralEdge {
+                halfedge: 3 * triangle + k,
+                pair,
+                normal,
+                pair_normal,
+                angle: dot(normal, pair_normal)
+                    .clamp(-1.0, 1.0)
+                    .acos()
+                    .to_degrees(),
+            });
+        }
+    }
+    edges
+}
+
+/// The triangles of a manifold over its distinct vertex positions, ignoring all other
+/// vertex properties.
+pub(crate) struct TriangleMesh {
+    pub(crate) positions: Vec<Vector>,
+    pub(crate) triangles: Vec<[usize; 3]>,
+}
+
+impl TriangleMesh {
+    pub(crate) fn of_manifold(manifold: &Manifold) -> TriangleMesh {
+        TriangleMesh::of_mesh_gl(&manifold.as_mesh64())
+    }
+
+    /// Keeps the triangle order of the mesh, so triangle indices match those of the
+    /// [`MeshGL64`].
+    pub(crate) fn of_mesh_gl(mesh_gl: &MeshGL64) -> TriangleMesh {
+        let properties = mesh_gl.vertex_properties();
+        let properties_per_vertex = mesh_gl.properties_per_vertex_count();
+        let merged = merged_vertices(
+            mesh_gl.vertex_count(),
+            mesh_gl.merge_from_vertex_indices(),
+            mesh_gl.merge_to_vertex_indices(),
+        );
+
+        let mut index_of_vertex = vec![usize::MAX; merged.len()];
+        let mut positions = Vec::new();
+        for (vertex, &target) in merged.iter().enumerate() {
+            if vertex == target {
+                let offset = vertex * properties_per_vertex;
+                index_of_vertex[vertex] = positions.len();
+                positions.push([
+                    properties[offset],
+                    properties[offset + 1],
+                    properties[offset + 2],
+                ]);
+            }
+        }
+
+        let triangles = mesh_gl
+            .vertex_indices()
+            .chunks_exact(3)
+            .map(|triangle| {
+                [0, 1, 2].map(|corner| index_of_vertex[merged[triangle[corner] as usize]])
+            })
+            .collect();
+        TriangleMesh {
+            positions,
+            triangles,
+        }
+    }
+
+    pub(crate) fn corners(&self, triangle: usize) -> [Vector; 3] {
+        self.triangles[triangle].map(|vertex| self.positions[vertex])
+    }
+}
@@ -0,0 +1,141 @@
+use crate::manifold::geometry::{length, orthonormal_basis, scale};
+use crate::manifold::{BooleanOperation, Manifold};
+use crate::types::{Matrix4x3, Point3, PositiveF64, PositiveI32, Vec3};
+
+/// A cylindrical hole draining the cavity of a hollowed manifold, see [`Manifold::hollow`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrainHole {
+    /// The point on the surface where the hole starts.
+    pub position: Point3,
+    /// The direction from the surface into the manifold along which the hole is drilled.
+    pub direction: Vec3,
+    pub diameter: PositiveF64,
+}
+
+impl DrainHole {
+    pub fn new(
+        position: impl Into<Point3>,
+        direction: impl Into<Vec3>,
+        diameter: impl Into<PositiveF64>,
+    ) -> Self {
+        Self {
+            position: position.into(),
+            direction: direction.into(),
+            diameter: diameter.into(),
+        }
+    }
+}
+
+/// The result of [`Manifold::hollow`].
+pub struct Hollowing {
+    pub manifold: Manifold,
+    pub volume_before: f64,
+    pub volume_after: f64,
+}
+
+impl Hollowing {
+    /// Returns the volume removed by hollowing, e.g. to estimate the saved material.
+    pub fn volume_saved(&self) -> f64 {
+        self.volume_before - self.volume_after
+    }
+}
+
+impl Manifold {
+    /// Hollows the manifold into a shell of uniform wall thickness, e.g. for resin printing,
+    /// and drills drain holes through the wall.
+    ///
+    /// The cavity is the level set of the points deeper inside than the wall thickness, see
+    /// [`Manifold::from_level_set`]. Its grid uses an edge length of half the wall
+    /// thickness, coarsened to at most 128 cells along the largest dimension of the manifold,
+    /// so the cavity follows the surface more loosely on parts which are large compared to
+    /// their walls. Parts thinner than twice the wall thickness stay solid.
+    ///
+    /// Each drain hole is a cylinder from just outside the surface through twice the wall
+    /// thickness along its direction, which pierces the wall when drilled along the surface
+    /// normal.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::manifold::DrainHole;
+    /// use manifold3d::types::{Point3, PositiveF64, Vec3};
+    /// use manifold3d::Manifold;
+    ///
+    /// let cube = Manifold::new_cuboid(
+    ///     PositiveF64::new(10.0).unwrap(),
+    ///     PositiveF64::new(10.0).unwrap(),
+    ///     PositiveF64::new(10.0).unwrap(),
+    ///     true,
+    /// );
+    /// let drain = DrainHole::new(
+    ///     Point3::new(0.0, 0.0, -5.0),
+    ///     Vec3::new(0.0, 0.0, 1.0),
+    ///     PositiveF64::new(1.0).unwrap(),
+    /// );
+    ///
+    /// let hollowing = cube.hollow(PositiveF64::new(1.0).unwrap(), &[drain]);
+    /// assert!(hollowing.volume_saved() > 400.0);
+    /// assert_eq!(hollowing.manifold.genus(), 0);
+    /// ```
+    pub fn hollow(&self, wall_thickness: PositiveF64, drain_holes: &[DrainHole]) -> Hollowing {
+        let volume_before = self.volume();
+        if self.is_empty() {
+            return Hollowing {
+                manifold: self.clone(),
+                volume_before,
+                volume_after: volume_before,
+            };
+        }
+
+        let wall_thickness = wall_thickness.get();
+        let cavity = self.offset_surface(-wall_thickness, self.offset_edge_length(wall_thickness));
+
+        let holes = drain_holes
+            .iter()
+            .filter_map(|hole| drill(hole, wall_thickness))
+            .collect::<Vec<_>>();
+        let manifold = self.batch_boolean(
+            &[cavity].into_iter().chain(holes).collect::<Vec<_>>(),
+            BooleanOperation::Subtract,
+        );
+
+        let volume_after = manifold.volume();
+        Hollowing {
+            manifold,
+            volume_before,
+            volume_after,
+        }
+    }
+}
+
+/// Returns the cylinder of the drain hole, or `None` if its direction is zero.
+fn drill(hole: &DrainHole, wall_thickness: f64) -> Option<Manifold> {
+    let direction = [hole.direction.x, hole.direction.y, hole.direction.z];
+    let direction_length = length(direction);
+    if direction_length == 0.0 || !direction_length.is_finite() {
+        return None;
+    }
+    let direction = scale(direction, 1.0 / direction_length);
+    let (u, v) = orthonormal_basis(direction);
+    let radius = hole.diameter.get() / 2.0;
+    // Start outside the surface, so the hole does not leave a sliver at its mouth
+    let overshoot = radius.max(wall_thickness);
+    let start = [
+        hole.position.x - direction[0] * overshoot,
+        hole.position.y - direction[1] * overshoot,
+        hole.position.z - direction[2] * overshoot,
+    ];
+
+    let cylinder = Manifold::new_cylinder(
+        PositiveF64::new(overshoot + 2.0 * wall_thickness).unwrap(),
+        PositiveF64::new(radius).unwrap(),
+        None::<PositiveF64>,
+        None::<PositiveI32>,
+        false,
+    );
+    Some(cylinder.transform(Matrix4x3::new([
+        Vec3::new(u[0], u[1], u[2]),
+        Vec3::new(v[0], v[1], v[2]),
+        Vec3::new(direction[0], direction[1], direction[2]),
+        Vec3::new(start[0], start[1], start[2]),
+    ])))
+}
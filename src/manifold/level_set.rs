@@ -0,0 +1,234 @@
+use crate::bounding_box::BoundingBox;
+use crate::manifold::Manifold;
+use crate::types::{Point3, PositiveF64};
+use manifold3d_sys::{manifold_alloc_manifold, manifold_level_set};
+use std::os::raw::c_void;
+use std::pin::Pin;
+
+/// The signature of the `extern "C"` function the C API calls to sample a signed-distance
+/// function.
+pub type ExternCSdfFnPtr = unsafe extern "C" fn(x: f64, y: f64, z: f64, ctx: *mut c_void) -> f64;
+
+/// A trait that combines the functionality of [SignedDistance] and [ExternCSdfFn].
+///
+/// This trait is automatically implemented by the [manifold3d::manifold::sdf](crate::manifold::sdf)
+/// macro, which ensures that both [SignedDistance] and [ExternCSdfFn] are implemented
+/// for the annotated struct.
+///
+/// The signed-distance function is evaluated in parallel, so implementors have to be [Sync].
+///
+/// # Context
+/// [Sdf] is used in conjunction with the [Manifold::from_level_set] method to create a
+/// manifold from an implicit surface. The user needs to implement the [SignedDistance] trait
+/// to define the surface.
+pub trait Sdf: SignedDistance + ExternCSdfFn + Sync {}
+
+/// A trait for defining a signed-distance function.
+///
+/// This is the core functionality that you need to implement when using the
+/// [manifold3d::manifold::sdf](crate::manifold::sdf) macro.
+///
+/// # Example
+/// ```
+/// use manifold3d::macros::manifold;
+/// use manifold3d::manifold::SignedDistance;
+/// use manifold3d::types::Point3;
+///
+/// #[manifold::sdf]
+/// struct Gyroid {
+///     period: f64,
+/// }
+///
+/// impl SignedDistance for Gyroid {
+///     fn signed_distance(&self, point: Point3) -> f64 {
+///         let scale = std::f64::consts::TAU / self.period;
+///         let (x, y, z) = (point.x * scale, point.y * scale, point.z * scale);
+///         x.sin() * y.cos() + y.sin() * z.cos() + z.sin() * x.cos()
+///     }
+/// }
+/// ```
+pub trait SignedDistance {
+    /// Evaluates the signed distance at a point.
+    ///
+    /// # Arguments
+    /// - `point`: The sampled point.
+    ///
+    /// # Returns
+    /// The signed distance of the point to the surface. Positive values are inside,
+    /// negative values are outside.
+    fn signed_distance(&self, point: Point3) -> f64;
+}
+
+/// A trait for providing an `extern "C"` function pointer evaluating a signed-distance function.
+///
+/// This trait is automatically implemented by the
+/// [manifold3d::manifold::sdf](crate::manifold::sdf) macro. It provides
+/// a function pointer that can be passed to the
+/// [manifold_level_set](crate::sys::manifold_level_set) C function.
+///
+/// Users typically do not need to implement this trait manually; instead, it is
+/// derived by the macro.
+///
+/// # Safety
+/// The function pointer returned by this trait must be used correctly, adhering to
+/// C-style calling conventions. Improper use can lead to undefined behavior.
+pub trait ExternCSdfFn {
+    /// Returns a function pointer to an `extern "C"` function evaluating the signed
+    /// distance.
+    ///
+    /// # Safety
+    /// - The caller must ensure that the `ctx` pointer passed to the function
+    ///   points to a valid instance of the struct implementing the trait.
+    fn extern_c_sdf_fn(&self) -> ExternCSdfFnPtr;
+}
+
+impl Manifold {
+    /// Constructs a manifold from the level set of a signed-distance function, e.g. gyroid
+    /// infills or smooth organic blends.
+    ///
+    /// The function is sampled on a body-centered cubic grid inside `bounds` and the surface
+    /// at `level` is extracted with marching tetrahedra. The result is closed where it is cut
+    /// off by the bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `sdf`: A pinned reference to a type implementing the [Sdf] trait, see
+    ///   [SignedDistance] for an example. Positive values are inside.
+    /// * `bounds`: The region in which the function is sampled.
+    /// * `edge_length`: The approximate maximum edge length of the resulting triangles.
+    ///   Smaller values give finer detail, but the sample count grows cubically.
+    /// * `level`: The level of the extracted surface. Positive values inset the surface,
+    ///   negative values outset it.
+    /// * `tolerance`: The maximum distance of the vertices to the exact surface. Vertices
+    ///   are iteratively moved onto the surface until they are within this distance, which
+    ///   takes more evaluations of the function for smaller values. Without a tolerance the
+    ///   vertices are not refined and stay at the crossing point interpolated between their
+    ///   two nearest grid points.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::macros::manifold;
+    /// use manifold3d::manifold::SignedDistance;
+    /// use manifold3d::types::{Point3, PositiveF64};
+    /// use manifold3d::{BoundingBox, Manifold};
+    /// use std::pin::Pin;
+    ///
+    /// #[manifold::sdf]
+    /// struct Sphere {
+    ///     radius: f64,
+    /// }
+    ///
+    /// impl SignedDistance for Sphere {
+    ///     fn signed_distance(&self, point: Point3) -> f64 {
+    ///         self.radius - (point.x * point.x + point.y * point.y + point.z * point.z).sqrt()
+    ///     }
+    /// }
+    ///
+    /// let sphere = Sphere { radius: 1.0 };
+    /// let manifold = Manifold::from_level_set(
+    ///     Pin::new(&sphere),
+    ///     &BoundingBox::new(Point3::new(-1.5, -1.5, -1.5), Point3::new(1.5, 1.5, 1.5)),
+    ///     PositiveF64::new(0.1).unwrap(),
+    ///     0.0,
+    ///     None,
+    /// );
+    /// assert!(!manifold.is_empty());
+    /// ```
+    pub fn from_level_set(
+        sdf: Pin<&impl Sdf>,
+        bounds: &BoundingBox,
+        edge_length: PositiveF64,
+        level: f64,
+        tolerance: Option<PositiveF64>,
+    ) -> Manifold {
+        let sdf_ptr = &raw const *sdf;
+        Self::level_set(
+            sdf.extern_c_sdf_fn(),
+            sdf_ptr as *mut c_void,
+            bounds,
+            edge_length,
+            level,
+            tolerance,
+        )
+    }
+
+    /// Constructs a manifold from the level set of a signed-distance function given as a
+    /// closure.
+    ///
+    /// This behaves like [Manifold::from_level_set], without the need to define a type
+    /// implementing [Sdf].
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::{Point3, PositiveF64};
+    /// use manifold3d::{BoundingBox, Manifold};
+    ///
+    /// // A gyroid sheet of 0.3 thickness, clipped to a 10mm cube
+    /// let period = 5.0;
+    /// let gyroid = |point: Point3| {
+    ///     let scale = std::f64::consts::TAU / period;
+    ///     let (x, y, z) = (point.x * scale, point.y * scale, point.z * scale);
+    ///     0.3 - (x.sin() * y.cos() + y.sin() * z.cos() + z.sin() * x.cos()).abs()
+    /// };
+    /// let infill = Manifold::from_level_set_fn(
+    ///     gyroid,
+    ///     &BoundingBox::new(Point3::new(0.0, 0.0, 0.0), Point3::new(10.0, 10.0, 10.0)),
+    ///     PositiveF64::new(0.5).unwrap(),
+    ///     0.0,
+    ///     None,
+    /// );
+    /// assert!(infill.volume() < 1000.0);
+    /// ```
+    pub fn from_level_set_fn<F>(
+        sdf: F,
+        bounds: &BoundingBox,
+        edge_length: PositiveF64,
+        level: f64,
+        tolerance: Option<PositiveF64>,
+    ) -> Manifold
+    where
+        F: Fn(Point3) -> f64 + Sync,
+    {
+        unsafe extern "C" fn trampoline<F: Fn(Point3) -> f64>(
+            x: f64,
+            y: f64,
+            z: f64,
+            ctx: *mut c_void,
+        ) -> f64 {
+            let sdf = &*(ctx as *const F);
+            sdf(Point3::new(x, y, z))
+        }
+
+        Self::level_set(
+            trampoline::<F>,
+            &raw const sdf as *mut c_void,
+            bounds,
+            edge_length,
+            level,
+            tolerance,
+        )
+    }
+
+    fn level_set(
+        sdf: ExternCSdfFnPtr,
+        ctx: *mut c_void,
+        bounds: &BoundingBox,
+        edge_length: PositiveF64,
+        level: f64,
+        tolerance: Option<PositiveF64>,
+    ) -> Manifold {
+        let manifold_ptr = unsafe {
+            manifold_level_set(
+                manifold_alloc_manifold() as *mut c_void,
+                Some(sdf),
+                bounds.ptr(),
+                edge_length.get(),
+                level,
+                // Manifold does not refine the vertices for a negative tolerance
+                tolerance.map_or(-1.0, |tolerance| tolerance.get()),
+                ctx,
+            )
+        };
+        Manifold::from_ptr(manifold_ptr)
+    }
+}
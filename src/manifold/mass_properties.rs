@@ -0,0 +1,170 @@
+use crate::manifold::geometry::{cross, dot, TriangleMesh};
+use crate::manifold::Manifold;
+use crate::types::{Matrix3x3, Matrix4x3, Point3, PositiveF64, Vec3};
+
+/// The mass properties of a solid of uniform density, as returned by
+/// [`Manifold::mass_properties`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MassProperties {
+    pub mass: f64,
+    pub volume: f64,
+    pub center_of_mass: Point3,
+    /// The inertia tensor about the center of mass, in world axes.
+    pub inertia_tensor: Matrix3x3,
+    /// The inertia tensor about the origin.
+    pub inertia_tensor_about_origin: Matrix3x3,
+    /// The principal moments of inertia in ascending order.
+    pub principal_moments: Vec3,
+    /// The principal frame: the first three rows are the principal axes belonging to the
+    /// [`MassProperties::principal_moments`], forming a right-handed frame, and the last
+    /// row is the center of mass. Transforming by this frame maps the principal frame into
+    /// world space.
+    pub principal_axes: Matrix4x3,
+}
+
+impl Manifold {
+    /// Computes the mass, center of mass and inertia tensors of the manifold as a solid of
+    /// uniform density.
+    ///
+    /// The integrals are evaluated exactly over the tetrahedra spanned by the origin and each
+    /// triangle. An empty manifold has zero mass and its center of mass at the origin.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::PositiveF64;
+    /// use manifold3d::Manifold;
+    ///
+    /// let rod = Manifold::new_cuboid(
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     PositiveF64::new(4.0).unwrap(),
+    ///     false,
+    /// );
+    /// let properties = rod.mass_properties(PositiveF64::new(2.0).unwrap());
+    /// assert!((properties.mass - 8.0).abs() < 1e-9);
+    /// assert!((properties.center_of_mass.z - 2.0).abs() < 1e-9);
+    /// // The rod spins easiest about its long axis
+    /// assert!((properties.principal_axes.rows[0].z.abs() - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn mass_properties(&self, density: PositiveF64) -> MassProperties {
+        let density = density.get();
+        let mesh = TriangleMesh::of_manifold(self);
+
+        // The volume, its first moments and its second moments, integrated over the signed
+        // tetrahedra between the origin and each triangle
+        let mut volume = 0.0;
+        let mut first = [0.0; 3];
+        let mut second = [[0.0; 3]; 3];
+        for triangle in 0..mesh.triangles.len() {
+            let [a, b, c] = mesh.corners(triangle);
+            let determinant = dot(a, cross(b, c));
+            volume += determinant / 6.0;
+            let sum = [0, 1, 2].map(|axis| a[axis] + b[axis] + c[axis]);
+            for i in 0..3 {
+                first[i] += determinant / 24.0 * sum[i];
+                for j in 0..3 {
+                    second[i][j] += determinant / 120.0
+                        * (a[i] * a[j] + b[i] * b[j] + c[i] * c[j] + sum[i] * sum[j]);
+                }
+            }
+        }
+
+        let mass = density * volume;
+        let center = if volume != 0.0 {
+            first.map(|moment| moment / volume)
+        } else {
+            [0.0; 3]
+        };
+        let about_origin = inertia_of(second.map(|row| row.map(|value| value * density)));
+        let mut about_center = [[0.0; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                about_center[i][j] = density * second[i][j] - mass * center[i] * center[j];
+            }
+        }
+        let about_center = inertia_of(about_center);
+
+        let (moments, mut axes) = symmetric_eigen(about_center);
+        if dot(axes[0], cross(axes[1], axes[2])) < 0.0 {
+            axes[2] = axes[2].map(|value| -value);
+        }
+
+        MassProperties {
+            mass,
+            volume,
+            center_of_mass: Point3::new(center[0], center[1], center[2]),
+            inertia_tensor: to_matrix(about_center),
+            inertia_tensor_about_origin: to_matrix(about_origin),
+            principal_moments: Vec3::new(moments[0], moments[1], moments[2]),
+            principal_axes: Matrix4x3::new([
+                to_vec(axes[0]),
+                to_vec(axes[1]),
+                to_vec(axes[2]),
+                to_vec(center),
+            ]),
+        }
+    }
+}
+
+/// Converts the second moments `∫ x_i x_j dm` into the inertia tensor.
+fn inertia_of(second: [[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let trace = second[0][0] + second[1][1] + second[2][2];
+    let mut inertia = [[0.0; 3]; 3];
+    for i in 0..3 {
+        for j in 0..3 {
+            inertia[i][j] = if i == j { trace } else { 0.0 } - second[i][j];
+        }
+    }
+    inertia
+}
+
+/// Computes the eigenvalues in ascending order and the unit eigenvectors of a symmetric
+/// matrix with cyclic Jacobi rotations.
+fn symmetric_eigen(matrix: [[f64; 3]; 3]) -> ([f64; 3], [[f64; 3]; 3]) {
+    let mut a = matrix;
+    // The columns converge to the eigenvectors
+    let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    for _ in 0..50 {
+        let off_diagonal = a[0][1].powi(2) + a[0][2].powi(2) + a[1][2].powi(2);
+        let diagonal = a[0][0].powi(2) + a[1][1].powi(2) + a[2][2].powi(2);
+        if off_diagonal <= f64::EPSILON.powi(2) * diagonal {
+            break;
+        }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q] == 0.0 {
+                continue;
+            }
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let s = t * c;
+            for row in a.iter_mut() {
+                let (akp, akq) = (row[p], row[q]);
+                row[p] = c * akp - s * akq;
+                row[q] = s * akp + c * akq;
+            }
+            let (row_p, row_q) = (a[p], a[q]);
+            a[p] = [0, 1, 2].map(|k| c * row_p[k] - s * row_q[k]);
+            a[q] = [0, 1, 2].map(|k| s * row_p[k] + c * row_q[k]);
+            for row in v.iter_mut() {
+                let (vkp, vkq) = (row[p], row[q]);
+                row[p] = c * vkp - s * vkq;
+                row[q] = s * vkp + c * vkq;
+            }
+        }
+    }
+
+    let mut order = [0, 1, 2];
+    order.sort_by(|&i, &j| a[i][i].total_cmp(&a[j][j]));
+    let values = order.map(|i| a[i][i]);
+    let vectors = order.map(|i| [v[0][i], v[1][i], v[2][i]]);
+    (values, vectors)
+}
+
+fn to_vec(vector: [f64; 3]) -> Vec3 {
+    Vec3::new(vector[0], vector[1], vector[2])
+}
+
+fn to_matrix(matrix: [[f64; 3]; 3]) -> Matrix3x3 {
+    Matrix3x3::new(matrix.map(to_vec))
+}
@@ -0,0 +1,208 @@
+use crate::manifold::geometry::{TriangleMesh, Vector};
+use crate::manifold::{BooleanOperation, Manifold};
+use crate::types::{Point3, PositiveF64, Vec3};
+
+impl Manifold {
+    /// Returns the Minkowski sum of this manifold and another, i.e. the volume swept by
+    /// `other` when its origin is moved through all points of this manifold. This creates
+    /// e.g. clearance envelopes by summing a part with a tool sphere.
+    ///
+    /// If both manifolds are convex, the sum is the convex hull of all pairwise vertex sums.
+    /// Otherwise, both are decomposed into convex pieces: connected components which are
+    /// convex are kept whole, all others are split into their triangles. The convex hulls of
+    /// the vertex sums of all pairs of pieces are combined with translated copies of the
+    /// non-convex operands in a single [`Manifold::batch_boolean`]. The effort grows with the
+    /// product of the triangle counts of the non-convex operands, so keep those coarse.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::{PositiveF64, PositiveI32};
+    /// use manifold3d::Manifold;
+    ///
+    /// let cube = Manifold::new_cuboid(
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     true,
+    /// );
+    /// let tool = Manifold::new_sphere(
+    ///     PositiveF64::new(0.5).unwrap(),
+    ///     Some(PositiveI32::new(32).unwrap()),
+    /// );
+    ///
+    /// let envelope = cube.minkowski_sum(&tool);
+    /// let bounding_box = envelope.bounding_box();
+    /// assert!((bounding_box.dimensions().x - 3.0).abs() < 1e-9);
+    /// ```
+    pub fn minkowski_sum(&self, other: &Manifold) -> Manifold {
+        if self.is_empty() || other.is_empty() {
+            return Manifold::new_empty();
+        }
+        let operand = Operand::new(self);
+        let other_operand = Operand::new(other);
+
+        let mut parts = Vec::new();
+        for piece in &operand.pieces {
+            for other_piece in &other_operand.pieces {
+                let points = piece
+                    .iter()
+                    .flat_map(|a| {
+                        other_piece
+                            .iter()
+                            .map(move |b| Vec3::new(a[0] + b[0], a[1] + b[1], a[2] + b[2]))
+                    })
+                    .collect::<Vec<_>>();
+                let hull = Manifold::convex_hull_from_points(&points);
+                if !hull.is_empty() {
+                    parts.push(hull);
+                }
+            }
+        }
+
+        // The hulls only cover the boundary of a non-convex operand swept by the other one.
+        // Its interior is covered by translating it to a point of each shell of the other.
+        if !operand.is_convex {
+            for &[x, y, z] in &other_operand.shell_points {
+                parts.push(self.translate(Vec3::new(x, y, z)));
+            }
+        }
+        if !other_operand.is_convex {
+            for &[x, y, z] in &operand.shell_points {
+                parts.push(other.translate(Vec3::new(x, y, z)));
+            }
+        }
+        union_all(parts)
+    }
+
+    /// Returns the Minkowski difference of this manifold and another, i.e. all points at
+    /// which `other` fits completely into this manifold. This erodes the manifold, e.g. by
+    /// a tool sphere.
+    ///
+    /// The difference is computed as this manifold minus the [`Manifold::minkowski_sum`] of
+    /// its complement within an enlarged bounding box and the point reflection of `other`.
+    /// As the complement is never convex, keep the triangle count of `other` low.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::PositiveF64;
+    /// use manifold3d::Manifold;
+    ///
+    /// let cube = Manifold::new_cuboid(
+    ///     PositiveF64::new(4.0).unwrap(),
+    ///     PositiveF64::new(4.0).unwrap(),
+    ///     PositiveF64::new(4.0).unwrap(),
+    ///     true,
+    /// );
+    /// let tool = Manifold::new_cuboid(
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     true,
+    /// );
+    ///
+    /// let eroded = cube.minkowski_difference(&tool);
+    /// assert!((eroded.volume() - 27.0).abs() < 1e-9);
+    /// ```
+    pub fn minkowski_difference(&self, other: &Manifold) -> Manifold {
+        if self.is_empty() {
+            return Manifold::new_empty();
+        }
+        if other.is_empty() {
+            return self.clone();
+        }
+
+        let bounding_box = self.bounding_box();
+        let other_bounding_box = other.bounding_box();
+        let reach = [
+            other_bounding_box.min_point(),
+            other_bounding_box.max_point(),
+        ]
+        .iter()
+        .flat_map(|point| [point.x.abs(), point.y.abs(), point.z.abs()])
+        .fold(0.0, f64::max);
+        let margin = 2.0 * reach + bounding_box.scale() * 0.1;
+
+        let dimensions = bounding_box.dimensions();
+        // The margin is positive, as non-empty manifolds have a positive scale
+        let size = |extent: f64| PositiveF64::new(extent + 2.0 * margin).unwrap();
+        let enclosure = Manifold::new_cuboid(
+            size(dimensions.x),
+            size(dimensions.y),
+            size(dimensions.z),
+            true,
+        )
+        .translate(point_to_vec(bounding_box.center()));
+        let complement = enclosure.difference(self);
+        let reflected = other.scale(Vec3::new(-1.0, -1.0, -1.0));
+
+        self.difference(&complement.minkowski_sum(&reflected))
+    }
+}
+
+/// A Minkowski operand split into convex pieces.
+struct Operand {
+    /// The points spanning each convex piece.
+    pieces: Vec<Vec<Vector>>,
+    /// Whether all pieces are convex solids instead of triangles of the boundary.
+    is_convex: bool,
+    /// A point of each connected shell of the boundary.
+    shell_points: Vec<Vector>,
+}
+
+impl Operand {
+    fn new(manifold: &Manifold) -> Operand {
+        let components = manifold.decompose().as_vec();
+        let shell_points = components
+            .iter()
+            .map(|component| TriangleMesh::of_manifold(component).positions[0])
+            .collect();
+
+        // Inner shells of hollow manifolds are inside out on their own, so only separate
+        // components can be treated as convex solids.
+        let mut pieces = Vec::new();
+        let mut is_convex = true;
+        if components.iter().all(|component| component.volume() > 0.0) {
+            for component in &components {
+                let mesh = TriangleMesh::of_manifold(component);
+                if is_convex_solid(component) {
+                    pieces.push(mesh.positions);
+                } else {
+                    is_convex = false;
+                    pieces.extend(triangle_pieces(&mesh));
+                }
+            }
+        } else {
+            is_convex = false;
+            pieces = triangle_pieces(&TriangleMesh::of_manifold(manifold));
+        }
+
+        Operand {
+            pieces,
+            is_convex,
+            shell_points,
+        }
+    }
+}
+
+fn triangle_pieces(mesh: &TriangleMesh) -> Vec<Vec<Vector>> {
+    (0..mesh.triangles.len())
+        .map(|triangle| mesh.corners(triangle).to_vec())
+        .collect()
+}
+
+/// Checks whether the manifold encloses its convex hull up to its precision.
+pub(crate) fn is_convex_solid(manifold: &Manifold) -> bool {
+    manifold.convex_hull().volume() - manifold.volume()
+        <= manifold.epsilon() * manifold.surface_area()
+}
+
+fn union_all(parts: Vec<Manifold>) -> Manifold {
+    match parts.split_first() {
+        None => Manifold::new_empty(),
+        Some((first, rest)) => first.batch_boolean(rest, BooleanOperation::Add),
+    }
+}
+
+fn point_to_vec(point: Point3) -> Vec3 {
+    Vec3::new(point.x, point.y, point.z)
+}
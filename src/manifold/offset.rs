@@ -0,0 +1,98 @@
+use crate::bounding_box::BoundingBox;
+use crate::manifold::minkowski::is_convex_solid;
+use crate::manifold::Manifold;
+use crate::types::{PositiveF64, PositiveI32};
+
+/// The number of grid cells along the largest dimension of a manifold above which level set
+/// offsets coarsen their grid.
+const MAX_OFFSET_GRID_RESOLUTION: f64 = 128.0;
+
+/// The resolution of [`Manifold::offset`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct OffsetOptions {
+    /// The edge length of the level set grid. Defaults to half the distance, coarsened to at
+    /// most 128 cells along the largest dimension of the manifold.
+    pub edge_length: Option<PositiveF64>,
+    /// The number of segments of the sphere rounding the edges of grown convex manifolds.
+    /// Defaults to the quality settings, see [`Manifold::new_sphere`].
+    pub circular_segments: Option<PositiveI32>,
+}
+
+impl Manifold {
+    /// Grows the manifold by a positive distance or shrinks it by a negative distance, moving
+    /// its surface uniformly along the normals, e.g. to fit press fits or clearance gaps.
+    ///
+    /// Growing rounds the edges and corners of the manifold with the distance as radius,
+    /// while shrinking keeps concave edges rounded and convex edges sharp. Convex manifolds
+    /// are grown exactly by the [`Manifold::minkowski_sum`] with a sphere. All other offsets
+    /// are the level set of the signed distance to the surface, see
+    /// [`Manifold::from_level_set`], whose surface deviates from the exact offset by up to
+    /// the edge length of its grid. Parts thinner than twice a negative distance vanish.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::manifold::OffsetOptions;
+    /// use manifold3d::types::PositiveF64;
+    /// use manifold3d::Manifold;
+    ///
+    /// let cube = Manifold::new_cuboid(
+    ///     PositiveF64::new(10.0).unwrap(),
+    ///     PositiveF64::new(10.0).unwrap(),
+    ///     PositiveF64::new(10.0).unwrap(),
+    ///     true,
+    /// );
+    ///
+    /// let grown = cube.offset(0.5, &OffsetOptions::default());
+    /// assert!((grown.bounding_box().dimensions().x - 11.0).abs() < 1e-9);
+    /// // The corners are rounded
+    /// assert!(grown.volume() < 11.0 * 11.0 * 11.0);
+    ///
+    /// let shrunk = cube.offset(-0.5, &OffsetOptions::default());
+    /// assert!((shrunk.volume() - 9.0 * 9.0 * 9.0).abs() < 10.0);
+    /// ```
+    pub fn offset(&self, distance: f64, options: &OffsetOptions) -> Manifold {
+        if self.is_empty() || distance == 0.0 || !distance.is_finite() {
+            return self.clone();
+        }
+        if distance > 0.0 && is_convex_solid(self) {
+            let sphere = Manifold::new_sphere(
+                PositiveF64::new(distance).unwrap(),
+                options.circular_segments,
+            );
+            return self.minkowski_sum(&sphere);
+        }
+        let edge_length = options
+            .edge_length
+            .unwrap_or_else(|| self.offset_edge_length(distance));
+        self.offset_surface(distance, edge_length)
+    }
+
+    /// Returns half the distance as edge length of a level set grid, coarsened to at most
+    /// 128 cells along the largest dimension of the manifold.
+    pub(crate) fn offset_edge_length(&self, distance: f64) -> PositiveF64 {
+        let dimensions = self.bounding_box().dimensions();
+        let largest_dimension = dimensions.x.max(dimensions.y).max(dimensions.z);
+        PositiveF64::new((distance.abs() / 2.0).max(largest_dimension / MAX_OFFSET_GRID_RESOLUTION))
+            .unwrap()
+    }
+
+    /// Returns the solid whose surface lies at `distance` from the surface of this manifold,
+    /// outside for positive and inside for negative distances, as the level set of the
+    /// signed distance to the surface sampled on a grid with the edge length.
+    pub(crate) fn offset_surface(&self, distance: f64, edge_length: PositiveF64) -> Manifold {
+        let query = self.query();
+        let margin = distance.max(0.0) + edge_length.get();
+        let bounding_box = self.bounding_box();
+        let bounds = BoundingBox::new(
+            bounding_box.min_point() - margin,
+            bounding_box.max_point() + margin,
+        );
+        Manifold::from_level_set_fn(
+            |point| query.signed_distance(point) + distance,
+            &bounds,
+            edge_length,
+            0.0,
+            None,
+        )
+    }
+}
@@ -0,0 +1,182 @@
+use crate::manifold::geometry::{
+    cross, length, orthonormal_basis, scale, sub, TriangleMesh, Vector,
+};
+use crate::manifold::{BooleanOperation, Manifold};
+use crate::types::{Matrix4x3, NormalizedAngle, Vec3};
+use crate::Polygons;
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq)]
+pub enum OverhangAnalysisError {
+    #[error("the build direction {0:?} is not a finite, non-zero vector")]
+    InvalidBuildDirection(Vec3),
+}
+
+/// The result of [`Manifold::overhang_analysis`].
+pub struct OverhangAnalysis {
+    /// The total area of the overhanging triangles.
+    pub overhang_area: f64,
+    /// The regions of the build plate below the overhanging triangles, in the X-Y plane of
+    /// the [`OverhangAnalysis::plate_frame`].
+    pub footprint: Polygons,
+    /// The suggested support: the overhanging triangles extruded down to the build plate,
+    /// without the manifold itself.
+    pub support: Manifold,
+    /// The frame of the build plate: transforming the manifold by it turns the build
+    /// direction into the Z axis and puts the lowest point of the manifold at Z = 0.
+    pub plate_frame: Matrix4x3,
+}
+
+impl Manifold {
+    /// Finds the triangles of the manifold which overhang when building it layer by layer
+    /// along the build direction, e.g. with a 3D printer, and suggests support for them.
+    ///
+    /// A triangle overhangs if it faces downward and its angle from the build direction
+    /// exceeds `max_angle`: a vertical wall has an angle of 0°, a ceiling an angle of 90°.
+    /// Triangles lying on the build plate are supported by it. The lowest point of the
+    /// manifold along the build direction determines the build plate.
+    ///
+    /// # Arguments
+    ///
+    /// * `build_direction`: The direction in which the layers stack, pointing away from the
+    ///   build plate.
+    /// * `max_angle`: The largest angle from the build direction which can be built without
+    ///   support, usually around 45°. It is clamped to the range [0°, 90°].
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::{NormalizedAngle, PositiveF64, Vec3};
+    /// use manifold3d::Manifold;
+    ///
+    /// let cube = Manifold::new_cuboid(
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     false,
+    /// );
+    /// // A table: a top resting on a single leg
+    /// let table = cube.translate(Vec3::new(1.0, 1.0, 0.0)).union(
+    ///     &cube
+    ///         .scale(Vec3::new(3.0, 3.0, 0.5))
+    ///         .translate(Vec3::new(0.0, 0.0, 1.0)),
+    /// );
+    ///
+    /// let analysis = table
+    ///     .overhang_analysis(Vec3::new(0.0, 0.0, 1.0), NormalizedAngle::from_degrees(45.0))
+    ///     .unwrap();
+    /// // The underside of the top around the leg overhangs
+    /// assert!((analysis.overhang_area - 8.0).abs() < 1e-9);
+    /// assert!((analysis.support.volume() - 8.0).abs() < 1e-9);
+    /// ```
+    pub fn overhang_analysis(
+        &self,
+        build_direction: impl Into<Vec3>,
+        max_angle: NormalizedAngle,
+    ) -> Result<OverhangAnalysis, OverhangAnalysisError> {
+        let build_direction = build_direction.into();
+        let direction = [build_direction.x, build_direction.y, build_direction.z];
+        let direction_length = length(direction);
+        if direction_length == 0.0 || !direction_length.is_finite() {
+            return Err(OverhangAnalysisError::InvalidBuildDirection(
+                build_direction,
+            ));
+        }
+        let plate_frame = self.plate_frame(scale(direction, 1.0 / direction_length));
+        let on_plate = self.transform(plate_frame);
+
+        let min_downward = max_angle.get().clamp(0.0, 90.0).to_radians().sin();
+        let (overhang_area, region) = overhang_region(&on_plate, min_downward);
+
+        let Some(region) = region else {
+            return Ok(OverhangAnalysis {
+                overhang_area,
+                footprint: Polygons::from_simple_polygons(Vec::new()),
+                support: Manifold::new_empty(),
+                plate_frame,
+            });
+        };
+        // The prisms stand on the plate, so the outline of the projection is the outline of
+        // their bottom and does not self-intersect
+        let footprint = region.project();
+        let support = region
+            .difference(&on_plate)
+            .transform(inverse_rigid(plate_frame));
+
+        Ok(OverhangAnalysis {
+            overhang_area,
+            footprint,
+            support,
+            plate_frame,
+        })
+    }
+
+    /// Returns the rigid transformation which turns the unit build direction into the Z axis
+    /// and moves the lowest point of the manifold along it to Z = 0.
+    pub(crate) fn plate_frame(&self, direction: Vector) -> Matrix4x3 {
+        let (u, v) = orthonormal_basis(direction);
+        let rotation = Matrix4x3::new([
+            Vec3::new(u[0], v[0], direction[0]),
+            Vec3::new(u[1], v[1], direction[1]),
+            Vec3::new(u[2], v[2], direction[2]),
+            Vec3::new(0.0, 0.0, 0.0),
+        ]);
+        let lowest = self.transform(rotation).bounding_box().min_point().z;
+        Matrix4x3::new([
+            rotation.rows[0],
+            rotation.rows[1],
+            rotation.rows[2],
+            Vec3::new(0.0, 0.0, -lowest),
+        ])
+    }
+}
+
+/// Returns the area of the overhanging triangles of a manifold standing on the X-Y plane and
+/// the union of the prisms between them and the plane, if there are any.
+///
+/// A triangle overhangs if the downward component of its unit normal exceeds `min_downward`.
+pub(crate) fn overhang_region(on_plate: &Manifold, min_downward: f64) -> (f64, Option<Manifold>) {
+    let mesh = TriangleMesh::of_manifold(on_plate);
+    let plate_tolerance = on_plate
+        .epsilon()
+        .max(1e-9 * on_plate.bounding_box().scale());
+
+    let mut area = 0.0;
+    let mut prisms = Vec::new();
+    for triangle in 0..mesh.triangles.len() {
+        let corners = mesh.corners(triangle);
+        let [a, b, c] = corners;
+        let normal = cross(sub(b, a), sub(c, a));
+        let double_area = length(normal);
+        if double_area == 0.0 || -normal[2] / double_area <= min_downward {
+            continue;
+        }
+        if corners.iter().all(|corner| corner[2] <= plate_tolerance) {
+            continue;
+        }
+        area += double_area / 2.0;
+        let points = corners
+            .iter()
+            .flat_map(|&[x, y, z]| [Vec3::new(x, y, z), Vec3::new(x, y, 0.0)])
+            .collect::<Vec<_>>();
+        prisms.push(Manifold::convex_hull_from_points(&points));
+    }
+    let region = prisms
+        .split_first()
+        .map(|(first, others)| first.batch_boolean(others, BooleanOperation::Add));
+    (area, region)
+}
+
+/// Inverts a transformation whose linear part is a rotation.
+pub(crate) fn inverse_rigid(matrix: Matrix4x3) -> Matrix4x3 {
+    let [x, y, z, translation] = matrix.rows;
+    // The rotation is inverted by its transpose, which then maps the translation back to
+    // the origin
+    let back =
+        |axis: Vec3| -(axis.x * translation.x + axis.y * translation.y + axis.z * translation.z);
+    Matrix4x3::new([
+        Vec3::new(x.x, y.x, z.x),
+        Vec3::new(x.y, y.y, z.y),
+        Vec3::new(x.z, y.z, z.z),
+        Vec3::new(back(x), back(y), back(z)),
+    ])
+}
@@ -0,0 +1,380 @@
+use crate::error::Error;
+use crate::manifold::geometry::TriangleMesh;
+use crate::manifold::Manifold;
+use crate::mesh_gl64::MeshGL64;
+use crate::types::{NormalizedAngle, Point2, Point3, PositiveF64, PositiveI32, Vec3};
+use crate::{get_circular_segments, Polygons, SimplePolygon};
+use std::f64::consts::TAU;
+
+/// The golden ratio, which spans the icosahedron and the dodecahedron.
+const PHI: f64 = 1.618_033_988_749_895;
+
+impl Manifold {
+    /// Creates a torus around the Z axis, centered at the origin.
+    ///
+    /// # Arguments
+    /// * `major_radius`: The distance from the Z axis to the center of the tube.
+    /// * `minor_radius`: The radius of the tube, which has to be smaller than the major
+    ///   radius.
+    /// * `circular_segments`: The number of segments around the tube and around the Z axis.
+    ///   If `None` is provided, the global quality defaults are used for both radii.
+    ///
+    /// # Returns
+    /// A torus, or [`Error::InvalidConstruction`] if the tube reaches the Z axis.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::{PositiveF64, PositiveI32};
+    /// use manifold3d::Manifold;
+    ///
+    /// let torus = Manifold::new_torus(
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     PositiveF64::new(0.5).unwrap(),
+    ///     None::<PositiveI32>,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(torus.genus(), 1);
+    /// ```
+    pub fn new_torus(
+        major_radius: impl Into<PositiveF64>,
+        minor_radius: impl Into<PositiveF64>,
+        circular_segments: Option<impl Into<PositiveI32>>,
+    ) -> Result<Manifold, Error> {
+        let (major_radius, minor_radius) = (major_radius.into().get(), minor_radius.into());
+        if minor_radius.get() >= major_radius {
+            return Err(Error::InvalidConstruction);
+        }
+        let circular_segments = circular_segments.map(Into::into);
+        let tube_segments = circular_segments
+            .unwrap_or_else(|| get_circular_segments(minor_radius))
+            .get();
+        let tube = (0..tube_segments)
+            .map(|segment| {
+                let angle = TAU * segment as f64 / tube_segments as f64;
+                Point2::new(
+                    major_radius + minor_radius.get() * angle.cos(),
+                    minor_radius.get() * angle.sin(),
+                )
+            })
+            .collect::<Vec<_>>();
+        Polygons::from_simple_polygons(vec![SimplePolygon::new_from_points(tube)])
+            .revolve(circular_segments, None::<NormalizedAngle>)
+    }
+
+    /// Creates a capsule along the Z axis: a cylinder capped by hemispheres.
+    ///
+    /// # Arguments
+    /// * `height`: The length of the cylindrical part, i.e. the distance between the centers
+    ///   of the hemispheres.
+    /// * `radius`: The radius of the cylinder and the hemispheres.
+    /// * `circular_segments`: The number of segments around the Z axis. If `None` is provided,
+    ///   the global quality defaults are used.
+    /// * `origin_at_center`: If true, the capsule is centered at the origin. Otherwise, its
+    ///   lowest point touches the origin.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::{PositiveF64, PositiveI32};
+    /// use manifold3d::Manifold;
+    ///
+    /// let capsule = Manifold::new_capsule(
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     None::<PositiveI32>,
+    ///     false,
+    /// );
+    /// assert!((capsule.bounding_box().dimensions().z - 4.0).abs() < 1e-9);
+    /// ```
+    pub fn new_capsule(
+        height: impl Into<PositiveF64>,
+        radius: impl Into<PositiveF64>,
+        circular_segments: Option<impl Into<PositiveI32>>,
+        origin_at_center: bool,
+    ) -> Manifold {
+        let (height, radius) = (height.into().get(), radius.into());
+        let bottom = if origin_at_center {
+            -height / 2.0
+        } else {
+            radius.get()
+        };
+        Manifold::hull_of_spheres(
+            &[
+                Vec3::new(0.0, 0.0, bottom),
+                Vec3::new(0.0, 0.0, bottom + height),
+            ],
+            radius,
+            circular_segments,
+        )
+    }
+
+    /// Creates a cuboid whose edges and corners are rounded with the corner radius.
+    ///
+    /// # Arguments
+    /// * `x_size`, `y_size`, `z_size`: The dimensions of the cuboid, including the rounding.
+    /// * `corner_radius`: The radius of the rounding, at most half of the smallest dimension.
+    /// * `circular_segments`: The number of segments of a full circle of the rounding. If
+    ///   `None` is provided, the global quality defaults are used.
+    /// * `origin_at_center`: If true, the cuboid is centered at the origin. Otherwise, it lies
+    ///   in the first octant like [`Manifold::new_cuboid`].
+    ///
+    /// # Returns
+    /// A rounded cuboid, or [`Error::InvalidConstruction`] if the corner radius exceeds half
+    /// of a dimension.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::{PositiveF64, PositiveI32};
+    /// use manifold3d::Manifold;
+    ///
+    /// let rounded = Manifold::new_rounded_cuboid(
+    ///     PositiveF64::new(4.0).unwrap(),
+    ///     PositiveF64::new(3.0).unwrap(),
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     PositiveF64::new(0.5).unwrap(),
+    ///     None::<PositiveI32>,
+    ///     true,
+    /// )
+    /// .unwrap();
+    /// assert!((rounded.bounding_box().dimensions().x - 4.0).abs() < 1e-9);
+    /// assert!(rounded.volume() < 24.0);
+    /// ```
+    pub fn new_rounded_cuboid(
+        x_size: impl Into<PositiveF64>,
+        y_size: impl Into<PositiveF64>,
+        z_size: impl Into<PositiveF64>,
+        corner_radius: impl Into<PositiveF64>,
+        circular_segments: Option<impl Into<PositiveI32>>,
+        origin_at_center: bool,
+    ) -> Result<Manifold, Error> {
+        let size = [x_size.into(), y_size.into(), z_size.into()].map(|size| size.get());
+        let corner_radius = corner_radius.into();
+        let radius = corner_radius.get();
+        if size.iter().any(|&size| 2.0 * radius > size) {
+            return Err(Error::InvalidConstruction);
+        }
+        let min = size.map(|size| if origin_at_center { -size / 2.0 } else { 0.0 } + radius);
+        let max = [0, 1, 2].map(|axis| min[axis] + size[axis] - 2.0 * radius);
+        let centers = (0..8)
+            .map(|corner| {
+                let [x, y, z] = [0, 1, 2].map(|axis| {
+                    if corner & (1 << axis) == 0 {
+                        min[axis]
+                    } else {
+                        max[axis]
+                    }
+                });
+                Vec3::new(x, y, z)
+            })
+            .collect::<Vec<_>>();
+        Ok(Manifold::hull_of_spheres(
+            &centers,
+            corner_radius,
+            circular_segments,
+        ))
+    }
+
+    /// Creates a cone along the Z axis with a pointed apex.
+    ///
+    /// # Arguments
+    /// * `height`: The distance from the base to the apex.
+    /// * `radius`: The radius of the base.
+    /// * `circular_segments`: The number of segments of the base. If `None` is provided, the
+    ///   global quality defaults are used.
+    /// * `origin_at_center`: If true, the cone is centered at the origin along Z. Otherwise,
+    ///   its base lies on the X-Y plane.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::{PositiveF64, PositiveI32};
+    /// use manifold3d::Manifold;
+    ///
+    /// let cone = Manifold::new_cone(
+    ///     PositiveF64::new(3.0).unwrap(),
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     Some(PositiveI32::new(4).unwrap()),
+    ///     false,
+    /// );
+    /// // A square pyramid with a diagonal of 2
+    /// assert!((cone.volume() - 2.0).abs() < 1e-9);
+    /// ```
+    pub fn new_cone(
+        height: impl Into<PositiveF64>,
+        radius: impl Into<PositiveF64>,
+        circular_segments: Option<impl Into<PositiveI32>>,
+        origin_at_center: bool,
+    ) -> Manifold {
+        // 0 segments triggers use of static quality defaults
+        let circular_segments = circular_segments.map_or(0, |c| c.into().get());
+        unsafe {
+            Manifold::new_cylinder_unchecked(
+                height.into().get(),
+                radius.into().get(),
+                0.0,
+                circular_segments,
+                origin_at_center,
+            )
+        }
+    }
+
+    /// Creates a regular octahedron centered at the origin, with its vertices on the axes.
+    ///
+    /// # Arguments
+    /// * `radius`: The distance from the center to the vertices.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::PositiveF64;
+    /// use manifold3d::Manifold;
+    ///
+    /// let octahedron = Manifold::new_octahedron(PositiveF64::new(1.0).unwrap());
+    /// assert_eq!(octahedron.vertex_count(), 6);
+    /// ```
+    pub fn new_octahedron(radius: impl Into<PositiveF64>) -> Manifold {
+        let vertices = (0..3).flat_map(|axis| {
+            [1.0, -1.0].map(|sign| {
+                let mut vertex = [0.0; 3];
+                vertex[axis] = sign;
+                vertex
+            })
+        });
+        Manifold::convex_hull_of_unit_vertices(vertices, radius.into())
+    }
+
+    /// Creates a regular icosahedron centered at the origin.
+    ///
+    /// # Arguments
+    /// * `radius`: The distance from the center to the vertices.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::PositiveF64;
+    /// use manifold3d::Manifold;
+    ///
+    /// let icosahedron = Manifold::new_icosahedron(PositiveF64::new(1.0).unwrap());
+    /// assert_eq!(icosahedron.vertex_count(), 12);
+    /// assert_eq!(icosahedron.triangle_count(), 20);
+    /// ```
+    pub fn new_icosahedron(radius: impl Into<PositiveF64>) -> Manifold {
+        let vertices = [1.0, -1.0].into_iter().flat_map(|a| {
+            [PHI, -PHI]
+                .into_iter()
+                .flat_map(move |b| cyclic_permutations([0.0, a, b]))
+        });
+        Manifold::convex_hull_of_unit_vertices(vertices, radius.into())
+    }
+
+    /// Creates a regular dodecahedron centered at the origin.
+    ///
+    /// # Arguments
+    /// * `radius`: The distance from the center to the vertices.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::PositiveF64;
+    /// use manifold3d::Manifold;
+    ///
+    /// let dodecahedron = Manifold::new_dodecahedron(PositiveF64::new(1.0).unwrap());
+    /// assert_eq!(dodecahedron.vertex_count(), 20);
+    /// ```
+    pub fn new_dodecahedron(radius: impl Into<PositiveF64>) -> Manifold {
+        let cube = (0..8)
+            .map(|corner| [0, 1, 2].map(|axis| if corner & (1 << axis) == 0 { 1.0 } else { -1.0 }));
+        let rectangles = [1.0, -1.0].into_iter().flat_map(|a| {
+            [PHI, -PHI]
+                .into_iter()
+                .flat_map(move |b| cyclic_permutations([0.0, a / PHI, b]))
+        });
+        Manifold::convex_hull_of_unit_vertices(cube.chain(rectangles), radius.into())
+    }
+
+    /// Creates a closed polyhedron from its vertices and faces.
+    ///
+    /// # Arguments
+    /// * `points`: The vertices of the polyhedron.
+    /// * `faces`: The faces of the polyhedron, each given by the indices of at least three
+    ///   points in counter-clockwise order when seen from outside. Faces with more than
+    ///   three points have to be convex and are triangulated as fans.
+    ///
+    /// # Returns
+    /// The polyhedron, or an [`Error`] if a face has fewer than three points, refers to a
+    /// point which does not exist or the faces do not form a closed manifold.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::Point3;
+    /// use manifold3d::Manifold;
+    ///
+    /// // A square pyramid
+    /// let points = [
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    ///     Point3::new(0.5, 0.5, 1.0),
+    /// ];
+    /// let faces = [
+    ///     vec![3, 2, 1, 0],
+    ///     vec![0, 1, 4],
+    ///     vec![1, 2, 4],
+    ///     vec![2, 3, 4],
+    ///     vec![3, 0, 4],
+    /// ];
+    /// let pyramid = Manifold::new_polyhedron(&points, &faces).unwrap();
+    /// assert!((pyramid.volume() - 1.0 / 3.0).abs() < 1e-9);
+    /// ```
+    pub fn new_polyhedron(points: &[Point3], faces: &[Vec<usize>]) -> Result<Manifold, Error> {
+        if faces.iter().any(|face| face.len() < 3) {
+            return Err(Error::InvalidConstruction);
+        }
+        let vertex_properties = points
+            .iter()
+            .flat_map(|point| [point.x, point.y, point.z])
+            .collect::<Vec<_>>();
+        let vertex_indices = faces
+            .iter()
+            .flat_map(|face| {
+                (1..face.len() - 1).flat_map(|corner| [face[0], face[corner], face[corner + 1]])
+            })
+            .map(|index| index as u64)
+            .collect::<Vec<_>>();
+        let mesh_gl = MeshGL64::new(&vertex_properties, 3, &vertex_indices)?;
+        Manifold::from_mesh_gl64(&mesh_gl)
+    }
+
+    /// Returns the convex hull of spheres of the radius around the centers.
+    fn hull_of_spheres(
+        centers: &[Vec3],
+        radius: PositiveF64,
+        circular_segments: Option<impl Into<PositiveI32>>,
+    ) -> Manifold {
+        let sphere = TriangleMesh::of_manifold(&Manifold::new_sphere(radius, circular_segments));
+        let points = centers
+            .iter()
+            .flat_map(|center| {
+                sphere
+                    .positions
+                    .iter()
+                    .map(|&[x, y, z]| Vec3::new(center.x + x, center.y + y, center.z + z))
+            })
+            .collect::<Vec<_>>();
+        Manifold::convex_hull_from_points(&points)
+    }
+
+    /// Returns the convex hull of the vertices, scaled to the radius.
+    fn convex_hull_of_unit_vertices(
+        vertices: impl Iterator<Item = [f64; 3]>,
+        radius: PositiveF64,
+    ) -> Manifold {
+        let points = vertices
+            .map(|vertex| {
+                let factor = radius.get() / vertex.iter().map(|x| x * x).sum::<f64>().sqrt();
+                Vec3::new(vertex[0] * factor, vertex[1] * factor, vertex[2] * factor)
+            })
+            .collect::<Vec<_>>();
+        Manifold::convex_hull_from_points(&points)
+    }
+}
+
+fn cyclic_permutations([a, b, c]: [f64; 3]) -> [[f64; 3]; 3] {
+    [[a, b, c], [c, a, b], [b, c, a]]
+}
@@ -0,0 +1,267 @@
+use crate::manifold::surface_index::{closest_point_on_triangle, SurfaceIndex};
+use crate::manifold::Manifold;
+use crate::types::{Point3, PositiveF64, Vec3};
+
+/// A point on the surface of a manifold, as returned by [`ManifoldQuery::closest_point`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SurfacePoint {
+    pub position: Point3,
+    /// The distance between the query point and the surface point.
+    pub distance: f64,
+    /// The index of the triangle containing the point, in the order of [`Manifold::as_mesh`]
+    /// and [`Manifold::as_mesh64`].
+    pub triangle_index: usize,
+    /// The weights of the triangle's three vertices which interpolate the point.
+    pub barycentric_coordinates: Vec3,
+}
+
+/// The closest points between two manifolds, as returned by
+/// [`ManifoldQuery::closest_points`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClosestPoints {
+    /// The distance between both points.
+    pub distance: f64,
+    /// The point on the surface of the manifold the query was called on.
+    pub on_self: SurfacePoint,
+    /// The point on the surface of the other manifold.
+    pub on_other: SurfacePoint,
+}
+
+/// A bounding volume hierarchy over the surface of a manifold, which answers spatial
+/// queries like point containment, signed distances and ray casts.
+///
+/// Building it takes time proportional to the triangle count, so build it once with
+/// [`Manifold::query`] to answer many queries. The queries on [`Manifold`] itself build a
+/// new one every call.
+pub struct ManifoldQuery {
+    pub(crate) index: SurfaceIndex,
+    epsilon: f64,
+}
+
+impl Manifold {
+    /// Builds the spatial index of the manifold to answer many queries.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::{Point3, PositiveF64, PositiveI32};
+    /// use manifold3d::Manifold;
+    ///
+    /// let sphere = Manifold::new_sphere(
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     Some(PositiveI32::new(32).unwrap()),
+    /// );
+    /// let query = sphere.query();
+    /// let inside = (0..100)
+    ///     .filter(|&i| query.contains_point(Point3::new(i as f64 * 0.02 + 0.01, 0.0, 0.0)))
+    ///     .count();
+    /// assert_eq!(inside, 50);
+    /// ```
+    pub fn query(&self) -> ManifoldQuery {
+        ManifoldQuery {
+            index: self.surface_index(),
+            epsilon: self.epsilon(),
+        }
+    }
+
+    /// Checks whether the point lies inside the manifold, see
+    /// [`ManifoldQuery::contains_point`].
+    pub fn contains_point(&self, point: impl Into<Point3>) -> bool {
+        self.query().contains_point(point)
+    }
+
+    /// Returns the signed distance of the point to the surface of the manifold, see
+    /// [`ManifoldQuery::signed_distance`].
+    pub fn signed_distance(&self, point: impl Into<Point3>) -> f64 {
+        self.query().signed_distance(point)
+    }
+
+    /// Finds the point on the surface of the manifold closest to the given point, see
+    /// [`ManifoldQuery::closest_point`].
+    pub fn closest_point(&self, point: impl Into<Point3>) -> Option<SurfacePoint> {
+        self.query().closest_point(point)
+    }
+
+    /// Finds the closest points between the surfaces of this manifold and another one, see
+    /// [`ManifoldQuery::closest_points`].
+    pub fn closest_points(
+        &self,
+        other: &Manifold,
+        search_length: impl Into<PositiveF64>,
+    ) -> Option<ClosestPoints> {
+        self.query().closest_points(&other.query(), search_length)
+    }
+
+    /// Builds the spatial index of the manifold's surface.
+    pub(crate) fn surface_index(&self) -> SurfaceIndex {
+        SurfaceIndex::new(&self.as_mesh64())
+    }
+}
+
+impl ManifoldQuery {
+    /// Checks whether the point lies inside the manifold. Points on the surface count as
+    /// inside.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::{Point3, PositiveF64};
+    /// use manifold3d::Manifold;
+    ///
+    /// let cube = Manifold::new_cuboid(
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     true,
+    /// );
+    /// let query = cube.query();
+    /// assert!(query.contains_point(Point3::new(0.25, 0.0, -0.25)));
+    /// assert!(!query.contains_point(Point3::new(0.75, 0.0, 0.0)));
+    /// ```
+    pub fn contains_point(&self, point: impl Into<Point3>) -> bool {
+        let point = point_to_vector(point.into());
+        match self.index.closest_point(point) {
+            None => false,
+            Some(closest) if closest.distance_squared.sqrt() <= self.epsilon => true,
+            Some(_) => self.index.contains(point),
+        }
+    }
+
+    /// Returns the signed distance of the point to the surface of the manifold. Like for
+    /// [`Manifold::from_level_set`], positive values are inside and negative values outside.
+    ///
+    /// Returns negative infinity for empty manifolds.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::{Point3, PositiveF64};
+    /// use manifold3d::Manifold;
+    ///
+    /// let cube = Manifold::new_cuboid(
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     true,
+    /// );
+    /// let query = cube.query();
+    /// assert_eq!(query.signed_distance(Point3::new(0.0, 0.0, 0.0)), 1.0);
+    /// assert_eq!(query.signed_distance(Point3::new(3.0, 0.0, 0.0)), -2.0);
+    /// ```
+    pub fn signed_distance(&self, point: impl Into<Point3>) -> f64 {
+        let point = point.into();
+        let Some(closest) = self.closest_point(point) else {
+            return f64::NEG_INFINITY;
+        };
+        if closest.distance <= self.epsilon || self.index.contains(point_to_vector(point)) {
+            closest.distance
+        } else {
+            -closest.distance
+        }
+    }
+
+    /// Finds the point on the surface of the manifold closest to the given point.
+    ///
+    /// Returns `None` for empty manifolds.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::{Point3, PositiveF64};
+    /// use manifold3d::Manifold;
+    ///
+    /// let cube = Manifold::new_cuboid(
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     true,
+    /// );
+    /// let closest = cube
+    ///     .query()
+    ///     .closest_point(Point3::new(0.5, 0.0, 3.0))
+    ///     .unwrap();
+    /// assert_eq!(closest.position, Point3::new(0.5, 0.0, 1.0));
+    /// assert_eq!(closest.distance, 2.0);
+    /// ```
+    pub fn closest_point(&self, point: impl Into<Point3>) -> Option<SurfacePoint> {
+        let closest = self.index.closest_point(point_to_vector(point.into()))?;
+        let [x, y, z] = closest.position;
+        let [u, v, w] = closest.barycentric;
+        Some(SurfacePoint {
+            position: Point3::new(x, y, z),
+            distance: closest.distance_squared.sqrt(),
+            triangle_index: closest.triangle,
+            barycentric_coordinates: Vec3::new(u, v, w),
+        })
+    }
+
+    /// Finds the two witness points, and the triangles they lie on, which achieve the minimum
+    /// distance between the surfaces of this manifold and another one, e.g. for clearance
+    /// annotations. This complements [`Manifold::minimum_gap`], which only returns the
+    /// distance.
+    ///
+    /// If the surfaces intersect, both points coincide on the intersection. Note that a
+    /// manifold completely inside the other one does not intersect its surface.
+    ///
+    /// # Returns
+    ///
+    /// The closest points, or `None` if the manifolds are at least `search_length` apart or
+    /// one of them is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::{PositiveF64, Vec3};
+    /// use manifold3d::Manifold;
+    ///
+    /// let a = Manifold::new_cuboid(
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     false,
+    /// );
+    /// let b = Manifold::new_tetrahedron().translate(Vec3::new(4.0, 0.5, 0.5));
+    ///
+    /// let (a, b) = (a.query(), b.query());
+    /// let closest = a.closest_points(&b, PositiveF64::new(10.0).unwrap()).unwrap();
+    /// assert_eq!(closest.distance, 2.0);
+    /// assert_eq!(closest.on_self.position.x, 1.0);
+    /// assert_eq!(closest.on_other.position.x, 3.0);
+    ///
+    /// assert!(a.closest_points(&b, PositiveF64::new(1.0).unwrap()).is_none());
+    /// ```
+    pub fn closest_points(
+        &self,
+        other: &ManifoldQuery,
+        search_length: impl Into<PositiveF64>,
+    ) -> Option<ClosestPoints> {
+        let pair = self
+            .index
+            .closest_pair(&other.index, search_length.into().get())?;
+        let distance = pair.distance_squared.sqrt();
+        Some(ClosestPoints {
+            distance,
+            on_self: surface_point(&self.index, pair.triangle, pair.point, distance),
+            on_other: surface_point(
+                &other.index,
+                pair.other_triangle,
+                pair.other_point,
+                distance,
+            ),
+        })
+    }
+}
+
+fn point_to_vector(point: Point3) -> [f64; 3] {
+    [point.x, point.y, point.z]
+}
+
+fn surface_point(
+    index: &SurfaceIndex,
+    triangle: usize,
+    position: [f64; 3],
+    distance: f64,
+) -> SurfacePoint {
+    let (_, [u, v, w]) = closest_point_on_triangle(position, index.mesh.corners(triangle));
+    SurfacePoint {
+        position: Point3::new(position[0], position[1], position[2]),
+        distance,
+        triangle_index: triangle,
+        barycentric_coordinates: Vec3::new(u, v, w),
+    }
+}
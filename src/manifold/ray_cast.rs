@@ -0,0 +1,165 @@
+use crate::manifold::geometry::{add, length, scale, Vector};
+use crate::manifold::{Manifold, ManifoldQuery};
+use crate::types::{Point3, Vec3};
+
+/// A ray starting at `origin` and extending into `direction`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    pub origin: Point3,
+    pub direction: Vec3,
+}
+
+impl Ray {
+    pub fn new(origin: impl Into<Point3>, direction: impl Into<Vec3>) -> Self {
+        Self {
+            origin: origin.into(),
+            direction: direction.into(),
+        }
+    }
+}
+
+/// An intersection of a ray with the surface of a manifold.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayCastHit {
+    pub position: Point3,
+    /// The distance between the ray origin and the hit.
+    pub distance: f64,
+    /// The index of the hit triangle, in the order of [`Manifold::as_mesh`] and
+    /// [`Manifold::as_mesh64`].
+    pub triangle_index: usize,
+    /// The weights of the triangle's three vertices which interpolate the hit.
+    pub barycentric_coordinates: Vec3,
+    /// The face ID of the hit triangle.
+    pub face_id: u64,
+    /// The original ID of the mesh the hit triangle originates from.
+    pub original_id: u32,
+    /// Whether the ray enters the manifold at the hit, as opposed to leaving it.
+    pub is_entering: bool,
+}
+
+impl Manifold {
+    /// Intersects a ray with the surface of the manifold, see [`ManifoldQuery::ray_cast`].
+    pub fn ray_cast(
+        &self,
+        origin: impl Into<Point3>,
+        direction: impl Into<Vec3>,
+        max_distance: f64,
+    ) -> Vec<RayCastHit> {
+        self.query().ray_cast(origin, direction, max_distance)
+    }
+
+    /// Intersects many rays with the surface of the manifold, see
+    /// [`ManifoldQuery::ray_cast_batch`].
+    pub fn ray_cast_batch(&self, rays: &[Ray], max_distance: f64) -> Vec<Vec<RayCastHit>> {
+        self.query().ray_cast_batch(rays, max_distance)
+    }
+}
+
+impl ManifoldQuery {
+    /// Intersects a ray with the surface of the manifold, e.g. for picking or line-of-sight
+    /// checks.
+    ///
+    /// The direction does not need to be normalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin`: The start of the ray.
+    /// * `direction`: The direction of the ray.
+    /// * `max_distance`: The maximum distance of hits from the origin, which may be infinite.
+    ///
+    /// # Returns
+    ///
+    /// All hits ordered by distance. A ray through an edge or vertex may hit all adjacent
+    /// triangles. A zero direction hits nothing.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::{Point3, PositiveF64, Vec3};
+    /// use manifold3d::Manifold;
+    ///
+    /// let cube = Manifold::new_cuboid(
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     true,
+    /// );
+    /// let hits = cube.query().ray_cast(
+    ///     Point3::new(0.2, 0.3, -5.0),
+    ///     Vec3::new(0.0, 0.0, 1.0),
+    ///     f64::INFINITY,
+    /// );
+    /// assert_eq!(hits.len(), 2);
+    /// assert_eq!(hits[0].distance, 4.0);
+    /// assert!(hits[0].is_entering);
+    /// assert_eq!(hits[1].position, Point3::new(0.2, 0.3, 1.0));
+    /// ```
+    pub fn ray_cast(
+        &self,
+        origin: impl Into<Point3>,
+        direction: impl Into<Vec3>,
+        max_distance: f64,
+    ) -> Vec<RayCastHit> {
+        self.cast(&Ray::new(origin, direction), max_distance)
+    }
+
+    /// Intersects many rays with the surface of the manifold, returning the hits of every ray
+    /// like [`ManifoldQuery::ray_cast`].
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::manifold::Ray;
+    /// use manifold3d::types::{Point3, PositiveF64, Vec3};
+    /// use manifold3d::Manifold;
+    ///
+    /// let cube = Manifold::new_cuboid(
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     true,
+    /// );
+    /// let rays = (0..100)
+    ///     .map(|i| {
+    ///         Ray::new(
+    ///             Point3::new(-5.0, i as f64 * 0.019 - 0.94, 0.1),
+    ///             Vec3::new(1.0, 0.0, 0.0),
+    ///         )
+    ///     })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let hits = cube.query().ray_cast_batch(&rays, 10.0);
+    /// assert!(hits.iter().all(|hits| hits.len() == 2));
+    /// ```
+    pub fn ray_cast_batch(&self, rays: &[Ray], max_distance: f64) -> Vec<Vec<RayCastHit>> {
+        rays.iter()
+            .map(|ray| self.cast(ray, max_distance))
+            .collect()
+    }
+
+    fn cast(&self, ray: &Ray, max_distance: f64) -> Vec<RayCastHit> {
+        let direction = [ray.direction.x, ray.direction.y, ray.direction.z];
+        let direction_length = length(direction);
+        if direction_length == 0.0 || direction_length.is_nan() {
+            return Vec::new();
+        }
+        let direction: Vector = direction.map(|component| component / direction_length);
+        let origin = [ray.origin.x, ray.origin.y, ray.origin.z];
+
+        self.index
+            .ray_hits(origin, direction, max_distance)
+            .into_iter()
+            .map(|hit| {
+                let [x, y, z] = add(origin, scale(direction, hit.distance));
+                let [u, v, w] = hit.barycentric;
+                RayCastHit {
+                    position: Point3::new(x, y, z),
+                    distance: hit.distance,
+                    triangle_index: hit.triangle,
+                    barycentric_coordinates: Vec3::new(u, v, w),
+                    face_id: self.index.face_ids[hit.triangle],
+                    original_id: self.index.original_ids[hit.triangle],
+                    is_entering: hit.is_entering,
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,383 @@
+use crate::error::Error;
+use crate::manifold::geometry::{add, cross, dot, length, merged_vertices, scale, sub, Vector};
+use crate::manifold::surface_index::{closest_point_on_triangle, SurfaceIndex};
+use crate::manifold::Manifold;
+use crate::mesh_gl64::MeshGL64;
+use crate::types::PositiveF64;
+
+/// The result of [`Manifold::simplify`].
+pub struct Simplification {
+    pub manifold: Manifold,
+    pub triangle_count_before: usize,
+    pub triangle_count_after: usize,
+}
+
+impl Simplification {
+    /// Returns how many triangles the simplification removed.
+    pub fn removed_triangle_count(&self) -> usize {
+        self.triangle_count_before - self.triangle_count_after
+    }
+}
+
+impl Manifold {
+    /// Coarsens the manifold by collapsing edges wherever the surface moves by less than
+    /// `tolerance`, e.g. after heavy [`Manifold::refine_to_edge_length`] or boolean work.
+    ///
+    /// Edges are collapsed shortest first. A collapse is skipped if it would make the mesh
+    /// non-manifold, flip or degenerate a triangle, or move the surface further than
+    /// `tolerance` from any vertex of the original mesh, including those removed by earlier
+    /// collapses. The tolerance is never smaller than [`Manifold::epsilon`]. Vertices on
+    /// property seams, e.g. of UV coordinates or normals, are kept.
+    ///
+    /// The C API offers no way to raise the internal tolerance of a manifold like the C++
+    /// `SetTolerance`, so [`Manifold::epsilon`] of the result is unchanged and there is no
+    /// `set_tolerance`. Like with [`Manifold::from_mesh_gl64`], the result is a new original
+    /// without the runs of the simplified manifold.
+    ///
+    /// # Returns
+    ///
+    /// The simplified manifold together with the triangle counts before and after, so
+    /// callers can budget mesh sizes.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::{NonNegativeF64, PositiveF64};
+    /// use manifold3d::Manifold;
+    ///
+    /// let cube = Manifold::new_cuboid(
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     true,
+    /// );
+    /// let refined = cube.refine_to_edge_length(NonNegativeF64::new(0.1).unwrap());
+    ///
+    /// let simplification = refined.simplify(PositiveF64::new(1e-6).unwrap()).unwrap();
+    /// assert_eq!(simplification.triangle_count_after, 12);
+    /// assert!(simplification.removed_triangle_count() > 0);
+    /// ```
+    pub fn simplify(&self, tolerance: PositiveF64) -> Result<Simplification, Error> {
+        let triangle_count_before = self.triangle_count();
+        let tolerance = tolerance.get().max(self.epsilon());
+
+        let mesh_gl = self.as_mesh64();
+        let mut simplifier = Simplifier::new(&mesh_gl);
+        while simplifier.collapse_pass(tolerance) {}
+
+        let manifold = simplifier.into_manifold(&mesh_gl)?;
+        Ok(Simplification {
+            triangle_count_after: manifold.triangle_count(),
+            triangle_count_before,
+            manifold,
+        })
+    }
+}
+
+/// Edge collapsing over the distinct vertex positions of a mesh, while keeping track of the
+/// property vertices each triangle corner refers to.
+struct Simplifier {
+    positions: Vec<Vector>,
+    /// The position of every property vertex.
+    position_of: Vec<usize>,
+    /// Whether a position has more than one property vertex.
+    is_seam: Vec<bool>,
+    /// Triangles as property vertex indices, `None` once collapsed.
+    triangles: Vec<Option<[usize; 3]>>,
+    /// The live triangles around every position.
+    triangles_of: Vec<Vec<usize>>,
+    /// The original surface, which every changed triangle is checked against.
+    original: SurfaceIndex,
+    /// Points sampling the surface of the original mesh, each kept with a live triangle
+    /// within tolerance of it.
+    samples_of: Vec<Vec<Vector>>,
+    live_position_count: usize,
+}
+
+impl Simplifier {
+    fn new(mesh_gl: &MeshGL64) -> Simplifier {
+        let properties = mesh_gl.vertex_properties();
+        let properties_per_vertex = mesh_gl.properties_per_vertex_count();
+        let merged = merged_vertices(
+            mesh_gl.vertex_count(),
+            mesh_gl.merge_from_vertex_indices(),
+            mesh_gl.merge_to_vertex_indices(),
+        );
+
+        let mut position_of = vec![0; merged.len()];
+        let mut positions = Vec::new();
+        let mut index_of_merged = vec![usize::MAX; merged.len()];
+        for vertex in 0..merged.len() {
+            let target = merged[vertex];
+            if index_of_merged[target] == usize::MAX {
+                let offset = target * properties_per_vertex;
+                index_of_merged[target] = positions.len();
+                positions.push([
+                    properties[offset],
+                    properties[offset + 1],
+                    properties[offset + 2],
+                ]);
+            }
+            position_of[vertex] = index_of_merged[target];
+        }
+
+        let triangles = mesh_gl
+            .vertex_indices()
+            .chunks_exact(3)
+            .map(|triangle| {
+                Some([
+                    triangle[0] as usize,
+                    triangle[1] as usize,
+                    triangle[2] as usize,
+                ])
+            })
+            .collect::<Vec<_>>();
+
+        let mut triangles_of = vec![Vec::new(); positions.len()];
+        let mut property_vertex_of = vec![usize::MAX; positions.len()];
+        let mut is_seam = vec![false; positions.len()];
+        for (index, triangle) in triangles.iter().enumerate() {
+            for vertex in triangle.unwrap() {
+                let position = position_of[vertex];
+                triangles_of[position].push(index);
+                if property_vertex_of[position] == usize::MAX {
+                    property_vertex_of[position] = vertex;
+                } else if property_vertex_of[position] != vertex {
+                    is_seam[position] = true;
+                }
+            }
+        }
+
+        let samples_of = triangles
+            .iter()
+            .map(|triangle| {
+                let corners = triangle
+                    .unwrap()
+                    .map(|vertex| positions[position_of[vertex]]);
+                surface_samples(corners)
+            })
+            .collect();
+
+        Simplifier {
+            original: SurfaceIndex::new(mesh_gl),
+            live_position_count: positions.len(),
+            positions,
+            position_of,
+            is_seam,
+            samples_of,
+            triangles,
+            triangles_of,
+        }
+    }
+
+    /// Tries to collapse every edge once, shortest first. Returns whether any edge was
+    /// collapsed.
+    fn collapse_pass(&mut self, tolerance: f64) -> bool {
+        let mut edges = Vec::new();
+        for triangle in self.triangles.iter().flatten() {
+            for corner in 0..3 {
+                let from = self.position_of[triangle[corner]];
+                let to = self.position_of[triangle[(corner + 1) % 3]];
+                let edge_length = length(sub(self.positions[to], self.positions[from]));
+                edges.push((edge_length, from, to));
+            }
+        }
+        edges.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut collapsed = false;
+        for (_, from, to) in edges {
+            // A tetrahedron is the smallest closed manifold
+            if self.live_position_count <= 4 {
+                break;
+            }
+            collapsed |= self.try_collapse(from, to, tolerance);
+        }
+        collapsed
+    }
+
+    /// Collapses the position `from` into `to` if the edge between them still exists and
+    /// the collapse keeps the mesh manifold and within tolerance.
+    fn try_collapse(&mut self, from: usize, to: usize, tolerance: f64) -> bool {
+        if self.is_seam[from] {
+            return false;
+        }
+        let (shared, kept): (Vec<usize>, Vec<usize>) = self.triangles_of[from]
+            .iter()
+            .partition(|&&triangle| self.corner_of(triangle, to).is_some());
+        if shared.len() != 2 {
+            return false;
+        }
+
+        // Link condition: the only common neighbors are the two opposite corners
+        let neighbors_of_from = self.neighbors(from);
+        let neighbors_of_to = self.neighbors(to);
+        let common = neighbors_of_from
+            .iter()
+            .filter(|neighbor| neighbors_of_to.contains(neighbor))
+            .count();
+        if common != 2 {
+            return false;
+        }
+
+        let corners_after = |simplifier: &Simplifier, triangle: usize| {
+            simplifier.triangles[triangle].unwrap().map(|vertex| {
+                let position = simplifier.position_of[vertex];
+                simplifier.positions[if position == from { to } else { position }]
+            })
+        };
+        for &triangle in &kept {
+            let corners = self.triangles[triangle]
+                .unwrap()
+                .map(|vertex| self.positions[self.position_of[vertex]]);
+            let old_normal = triangle_normal(corners);
+            let new_corners = corners_after(self, triangle);
+            let new_normal = triangle_normal(new_corners);
+            if length(new_normal) <= f64::EPSILON * length(old_normal)
+                || dot(old_normal, new_normal) <= 0.0
+                || surface_samples(new_corners)
+                    .into_iter()
+                    .any(|sample| self.deviates(sample, tolerance))
+            {
+                return false;
+            }
+        }
+
+        // The samples of the original surface kept with the changed triangles have to stay
+        // within tolerance of the surface around the collapsed edge
+        let mut patch = neighbors_of_from
+            .iter()
+            .flat_map(|&position| &self.triangles_of[position])
+            .copied()
+            .filter(|triangle| !shared.contains(triangle))
+            .collect::<Vec<_>>();
+        patch.sort_unstable();
+        patch.dedup();
+        let patch_corners = patch
+            .iter()
+            .map(|&triangle| corners_after(self, triangle))
+            .collect::<Vec<_>>();
+        let mut placements = Vec::new();
+        let displaced = shared
+            .iter()
+            .chain(&kept)
+            .flat_map(|&triangle| &self.samples_of[triangle]);
+        for &sample in displaced {
+            let closest = patch_corners
+                .iter()
+                .map(|&corners| {
+                    let projection = closest_point_on_triangle(sample, corners).0;
+                    (length(sub(projection, sample)), projection)
+                })
+                .enumerate()
+                .min_by(|a, b| a.1 .0.total_cmp(&b.1 .0));
+            let Some((index, (distance, projection))) = closest else {
+                return false;
+            };
+            // The changed triangles leave the original surface the most where the samples
+            // project onto them
+            if distance > tolerance
+                || (kept.contains(&patch[index]) && self.deviates(projection, tolerance))
+            {
+                return false;
+            }
+            placements.push((patch[index], sample));
+        }
+
+        // Both shared triangles refer to the same property vertex of `to`, as `from` is not
+        // on a seam
+        let shared_triangle = self.triangles[shared[0]].unwrap();
+        let target_vertex = shared_triangle[self.corner_of(shared[0], to).unwrap()];
+        for &triangle in &shared {
+            for vertex in self.triangles[triangle].unwrap() {
+                let position = self.position_of[vertex];
+                if position != from {
+                    self.triangles_of[position].retain(|&other| other != triangle);
+                }
+            }
+            self.triangles[triangle] = None;
+        }
+        for &triangle in shared.iter().chain(&kept) {
+            self.samples_of[triangle].clear();
+        }
+        for (triangle, sample) in placements {
+            self.samples_of[triangle].push(sample);
+        }
+        for &triangle in &kept {
+            let corner = self.corner_of(triangle, from).unwrap();
+            self.triangles[triangle].as_mut().unwrap()[corner] = target_vertex;
+            self.triangles_of[to].push(triangle);
+        }
+        self.triangles_of[from].clear();
+        self.live_position_count -= 1;
+        true
+    }
+
+    /// Checks whether a point is further than the tolerance from the original surface.
+    fn deviates(&self, point: Vector, tolerance: f64) -> bool {
+        self.original
+            .closest_point(point)
+            .is_none_or(|closest| closest.distance_squared > tolerance * tolerance)
+    }
+
+    fn corner_of(&self, triangle: usize, position: usize) -> Option<usize> {
+        self.triangles[triangle]?
+            .iter()
+            .position(|&vertex| self.position_of[vertex] == position)
+    }
+
+    fn neighbors(&self, position: usize) -> Vec<usize> {
+        let mut neighbors = self.triangles_of[position]
+            .iter()
+            .flat_map(|&triangle| self.triangles[triangle].unwrap())
+            .map(|vertex| self.position_of[vertex])
+            .filter(|&neighbor| neighbor != position)
+            .collect::<Vec<_>>();
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        neighbors
+    }
+
+    fn into_manifold(self, mesh_gl: &MeshGL64) -> Result<Manifold, Error> {
+        let properties = mesh_gl.vertex_properties();
+        let properties_per_vertex = mesh_gl.properties_per_vertex_count();
+
+        let mut index_of_vertex = vec![usize::MAX; self.position_of.len()];
+        let mut vertex_properties = Vec::new();
+        let mut vertex_indices = Vec::new();
+        for triangle in self.triangles.into_iter().flatten() {
+            for vertex in triangle {
+                if index_of_vertex[vertex] == usize::MAX {
+                    index_of_vertex[vertex] = vertex_properties.len() / properties_per_vertex;
+                    let offset = vertex * properties_per_vertex;
+                    vertex_properties
+                        .extend_from_slice(&properties[offset..offset + properties_per_vertex]);
+                }
+                vertex_indices.push(index_of_vertex[vertex] as u64);
+            }
+        }
+
+        let simplified = MeshGL64::new(&vertex_properties, properties_per_vertex, &vertex_indices)?;
+        let simplified = simplified.merge().unwrap_or(simplified);
+        Manifold::from_mesh_gl64(&simplified)
+    }
+}
+
+/// Samples a triangle at its corners, edge midpoints and centroid.
+fn surface_samples([a, b, c]: [Vector; 3]) -> Vec<Vector> {
+    let mean = |points: &[Vector]| {
+        let sum = points.iter().fold([0.0; 3], |sum, &point| add(sum, point));
+        scale(sum, 1.0 / points.len() as f64)
+    };
+    vec![
+        a,
+        b,
+        c,
+        mean(&[a, b]),
+        mean(&[b, c]),
+        mean(&[c, a]),
+        mean(&[a, b, c]),
+    ]
+}
+
+fn triangle_normal(corners: [Vector; 3]) -> Vector {
+    cross(sub(corners[1], corners[0]), sub(corners[2], corners[0]))
+}
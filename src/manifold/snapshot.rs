@@ -0,0 +1,268 @@
+use crate::error::Error;
+use crate::manifold::Manifold;
+use crate::mesh_gl64::{MeshGL64, MeshGL64Buffers};
+use thiserror::Error;
+
+const MAGIC: [u8; 4] = *b"MFD3";
+const VERSION: u16 = 1;
+const FLAG_ORIGINAL: u16 = 1;
+const HEADER_LENGTH: usize = 16;
+const CHECKSUM_LENGTH: usize = 4;
+
+/// Errors that can occur while loading a snapshot created by [`Manifold::to_bytes`].
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum SnapshotError {
+    #[error("the data is not a manifold snapshot")]
+    InvalidMagic,
+    #[error("snapshot version {0} is not supported, the latest supported version is {VERSION}")]
+    UnsupportedVersion(u16),
+    #[error("the snapshot is truncated")]
+    Truncated,
+    #[error(
+        "the snapshot checksum {actual:#010x} does not match the stored checksum {expected:#010x}"
+    )]
+    ChecksumMismatch { expected: u32, actual: u32 },
+    #[error("the snapshot contains an invalid mesh: {0:?}")]
+    InvalidMesh(Error),
+}
+
+/// The contents of a snapshot, as decoded by [`Manifold::decode_snapshot`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    /// The original ID of the saved manifold, or `None` if it was not an original.
+    pub original_id: Option<i32>,
+    /// All buffers of the saved manifold's [`MeshGL64`], see [`MeshGL64::from_buffers`].
+    pub mesh: MeshGL64Buffers,
+}
+
+impl Manifold {
+    /// Encodes the manifold into a compact binary snapshot, to be loaded again with
+    /// [`Manifold::from_bytes`].
+    ///
+    /// The snapshot contains the original ID of the manifold and every buffer of its double
+    /// precision [`MeshGL64`]: vertex properties, triangles, merge vectors, runs with their
+    /// original IDs and transforms, face IDs and halfedge tangents. All values are stored
+    /// little-endian, followed by a CRC-32 checksum.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::PositiveF64;
+    /// use manifold3d::Manifold;
+    ///
+    /// let manifold = Manifold::new_cuboid(
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     PositiveF64::new(2.0).unwrap(),
+    ///     PositiveF64::new(3.0).unwrap(),
+    ///     true,
+    /// );
+    ///
+    /// let bytes = manifold.to_bytes();
+    /// let loaded = Manifold::from_bytes(&bytes).unwrap();
+    /// assert_eq!(loaded.volume(), manifold.volume());
+    /// assert_eq!(loaded.genus(), manifold.genus());
+    ///
+    /// let snapshot = Manifold::decode_snapshot(&bytes).unwrap();
+    /// assert_eq!(snapshot.original_id, manifold.original_id());
+    /// assert_eq!(snapshot.mesh, manifold.as_mesh64().buffers());
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mesh = self.as_mesh64().buffers();
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+        let original_id = self.original_id();
+        let flags = if original_id.is_some() {
+            FLAG_ORIGINAL
+        } else {
+            0
+        };
+        bytes.extend_from_slice(&flags.to_le_bytes());
+        bytes.extend_from_slice(&original_id.unwrap_or(-1).to_le_bytes());
+        bytes.extend_from_slice(&(mesh.properties_per_vertex as u32).to_le_bytes());
+
+        write_values(&mut bytes, &mesh.vertex_properties);
+        write_values(&mut bytes, &mesh.vertex_indices);
+        write_values(&mut bytes, &mesh.merge_from_vertex_indices);
+        write_values(&mut bytes, &mesh.merge_to_vertex_indices);
+        write_values(&mut bytes, &mesh.run_indices);
+        write_values(&mut bytes, &mesh.run_original_ids);
+        write_values(&mut bytes, &mesh.run_transforms);
+        write_values(&mut bytes, &mesh.face_ids);
+        write_values(&mut bytes, &mesh.halfedge_tangents);
+
+        let checksum = crc32(&bytes);
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+        bytes
+    }
+
+    /// Loads a manifold from a snapshot created by [`Manifold::to_bytes`].
+    ///
+    /// The geometry, vertex properties and tangents are restored exactly, so `volume`,
+    /// `genus` and all other measures match those of the saved manifold.
+    ///
+    /// Manifold cannot give a mesh chosen original IDs, runs or face IDs, and original IDs are
+    /// only unique within one process. The loaded manifold is therefore a new original, see
+    /// [`Manifold::as_original`], so original IDs are remapped on load instead of reproduced.
+    /// The stored IDs, runs and face IDs can be read with [`Manifold::decode_snapshot`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Manifold, SnapshotError> {
+        let snapshot = Manifold::decode_snapshot(bytes)?;
+        let invalid = SnapshotError::InvalidMesh;
+        let mesh_gl = MeshGL64::from_buffers(&snapshot.mesh).map_err(invalid)?;
+        let manifold = Manifold::from_mesh_gl64(&mesh_gl).map_err(invalid)?;
+        Ok(manifold.as_original())
+    }
+
+    /// Decodes a snapshot created by [`Manifold::to_bytes`] without building a manifold,
+    /// returning every stored buffer including those [`Manifold::from_bytes`] cannot restore.
+    pub fn decode_snapshot(bytes: &[u8]) -> Result<Snapshot, SnapshotError> {
+        if bytes.len() < MAGIC.len() || bytes[..MAGIC.len()] != MAGIC {
+            return Err(SnapshotError::InvalidMagic);
+        }
+        if bytes.len() < HEADER_LENGTH + CHECKSUM_LENGTH {
+            return Err(SnapshotError::Truncated);
+        }
+        let mut reader = Reader {
+            bytes: &bytes[..bytes.len() - CHECKSUM_LENGTH],
+            position: MAGIC.len(),
+        };
+        let version = reader.u16()?;
+        if version != VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+        let expected =
+            u32::from_le_bytes(bytes[bytes.len() - CHECKSUM_LENGTH..].try_into().unwrap());
+        let actual = crc32(reader.bytes);
+        if expected != actual {
+            return Err(SnapshotError::ChecksumMismatch { expected, actual });
+        }
+
+        let flags = reader.u16()?;
+        let original_id = reader.u32()? as i32;
+        let mesh = MeshGL64Buffers {
+            properties_per_vertex: reader.u32()? as usize,
+            vertex_properties: reader.values()?,
+            vertex_indices: reader.values()?,
+            merge_from_vertex_indices: reader.values()?,
+            merge_to_vertex_indices: reader.values()?,
+            run_indices: reader.values()?,
+            run_original_ids: reader.values()?,
+            run_transforms: reader.values()?,
+            face_ids: reader.values()?,
+            halfedge_tangents: reader.values()?,
+        };
+        if reader.position != reader.bytes.len() {
+            return Err(SnapshotError::InvalidMesh(Error::InvalidConstruction));
+        }
+        Ok(Snapshot {
+            original_id: (flags & FLAG_ORIGINAL != 0).then_some(original_id),
+            mesh,
+        })
+    }
+}
+
+/// A value stored in a snapshot buffer.
+trait Value: Sized {
+    const SIZE: usize;
+
+    fn write(&self, bytes: &mut Vec<u8>);
+
+    fn read(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_value {
+    ($($ttype:ty),+) => {
+        $(
+            impl Value for $ttype {
+                const SIZE: usize = size_of::<$ttype>();
+
+                fn write(&self, bytes: &mut Vec<u8>) {
+                    bytes.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn read(bytes: &[u8]) -> Self {
+                    <$ttype>::from_le_bytes(bytes.try_into().unwrap())
+                }
+            }
+        )+
+    };
+}
+
+impl_value!(u32, u64, f64);
+
+fn write_values<T: Value>(bytes: &mut Vec<u8>, values: &[T]) {
+    bytes.extend_from_slice(&(values.len() as u64).to_le_bytes());
+    for value in values {
+        value.write(bytes);
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], SnapshotError> {
+        let end = self
+            .position
+            .checked_add(N)
+            .ok_or(SnapshotError::Truncated)?;
+        let taken = self
+            .bytes
+            .get(self.position..end)
+            .ok_or(SnapshotError::Truncated)?;
+        self.position = end;
+        Ok(taken.try_into().unwrap())
+    }
+
+    fn u16(&mut self) -> Result<u16, SnapshotError> {
+        Ok(u16::from_le_bytes(self.take()?))
+    }
+
+    fn u32(&mut self) -> Result<u32, SnapshotError> {
+        Ok(u32::from_le_bytes(self.take()?))
+    }
+
+    fn values<T: Value>(&mut self) -> Result<Vec<T>, SnapshotError> {
+        let length = u64::from_le_bytes(self.take()?);
+        // Checking the length first bounds the allocation by the input size
+        if length > ((self.bytes.len() - self.position) / T::SIZE) as u64 {
+            return Err(SnapshotError::Truncated);
+        }
+        let values = self.bytes[self.position..]
+            .chunks_exact(T::SIZE)
+            .take(length as usize)
+            .map(T::read)
+            .collect();
+        self.position += length as usize * T::SIZE;
+        Ok(values)
+    }
+}
+
+/// Computes the CRC-32 (IEEE 802.3) checksum of the data.
+fn crc32(data: &[u8]) -> u32 {
+    const TABLE: [u32; 256] = {
+        let mut table = [0u32; 256];
+        let mut index = 0;
+        while index < 256 {
+            let mut value = index as u32;
+            let mut bit = 0;
+            while bit < 8 {
+                value = if value & 1 != 0 {
+                    0xEDB8_8320 ^ (value >> 1)
+                } else {
+                    value >> 1
+                };
+                bit += 1;
+            }
+            table[index] = value;
+            index += 1;
+        }
+        table
+    };
+
+    !data.iter().fold(!0u32, |crc, byte| {
+        TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
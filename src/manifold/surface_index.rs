@@ -0,0 +1,544 @@
+//! A bounding volume hierarchy over the triangles of a manifold, built once per manifold and
+//! shared by all spatial queries.
+
+use crate::manifold::geometry::{add, cross, dot, length, scale, sub, TriangleMesh, Vector};
+use crate::mesh_gl64::MeshGL64;
+
+/// The maximum number of triangles in a leaf of the hierarchy.
+const LEAF_SIZE: usize = 4;
+
+pub(crate) struct SurfaceIndex {
+    pub(crate) mesh: TriangleMesh,
+    /// The face ID of every triangle.
+    pub(crate) face_ids: Vec<u64>,
+    /// The original ID of the run every triangle belongs to.
+    pub(crate) original_ids: Vec<u32>,
+    nodes: Vec<Node>,
+    /// The triangle indices, ordered such that every leaf covers a contiguous range.
+    order: Vec<usize>,
+}
+
+/// A node of the hierarchy. Leaves cover `count` triangles of the order starting at `first`,
+/// inner nodes have no triangles and their children at `first` and `first + 1`.
+#[derive(Clone, Copy)]
+struct Node {
+    min: Vector,
+    max: Vector,
+    first: usize,
+    count: usize,
+}
+
+impl Node {
+    /// Returns the length of the box's diagonal.
+    fn size(&self) -> f64 {
+        length(sub(self.max, self.min))
+    }
+}
+
+/// The closest point on the surface to a query point.
+pub(crate) struct ClosestHit {
+    pub(crate) triangle: usize,
+    pub(crate) position: Vector,
+    pub(crate) barycentric: Vector,
+    pub(crate) distance_squared: f64,
+}
+
+/// The closest points between two surfaces.
+pub(crate) struct ClosestPair {
+    pub(crate) triangle: usize,
+    pub(crate) point: Vector,
+    pub(crate) other_triangle: usize,
+    pub(crate) other_point: Vector,
+    pub(crate) distance_squared: f64,
+}
+
+/// An intersection of a ray with a triangle.
+pub(crate) struct RayHit {
+    pub(crate) triangle: usize,
+    pub(crate) distance: f64,
+    pub(crate) barycentric: Vector,
+    /// Whether the ray enters the manifold through the triangle.
+    pub(crate) is_entering: bool,
+}
+
+impl SurfaceIndex {
+    pub(crate) fn new(mesh_gl: &MeshGL64) -> SurfaceIndex {
+        let mesh = TriangleMesh::of_mesh_gl(mesh_gl);
+        let triangle_count = mesh.triangles.len();
+
+        let mut face_ids = mesh_gl.face_ids();
+        if face_ids.len() != triangle_count {
+            face_ids = (0..triangle_count as u64).collect();
+        }
+        let run_indices = mesh_gl.run_indices();
+        let mut original_ids = vec![0; triangle_count];
+        for (run, original_id) in mesh_gl.run_original_ids().into_iter().enumerate() {
+            let (Some(&start), Some(&end)) = (run_indices.get(run), run_indices.get(run + 1))
+            else {
+                break;
+            };
+            original_ids[(start / 3) as usize..(end / 3) as usize].fill(original_id);
+        }
+
+        let mut index = SurfaceIndex {
+            mesh,
+            face_ids,
+            original_ids,
+            nodes: Vec::new(),
+            order: (0..triangle_count).collect(),
+        };
+        index.build();
+        index
+    }
+
+    fn build(&mut self) {
+        if self.order.is_empty() {
+            return;
+        }
+        let centroids = (0..self.mesh.triangles.len())
+            .map(|triangle| {
+                let [a, b, c] = self.mesh.corners(triangle);
+                [0, 1, 2].map(|axis| (a[axis] + b[axis] + c[axis]) / 3.0)
+            })
+            .collect::<Vec<_>>();
+
+        self.nodes.push(self.leaf(0, self.order.len()));
+        let mut pending = vec![0];
+        while let Some(node) = pending.pop() {
+            let Node {
+                min,
+                max,
+                first,
+                count,
+            } = self.nodes[node];
+            if count <= LEAF_SIZE {
+                continue;
+            }
+            let extent = sub(max, min);
+            let axis = (0..3)
+                .max_by(|&a, &b| extent[a].total_cmp(&extent[b]))
+                .unwrap();
+            let middle = count / 2;
+            self.order[first..first + count].select_nth_unstable_by(middle, |&a, &b| {
+                centroids[a][axis].total_cmp(&centroids[b][axis])
+            });
+
+            let children = self.nodes.len();
+            self.nodes.push(self.leaf(first, middle));
+            self.nodes.push(self.leaf(first + middle, count - middle));
+            self.nodes[node].first = children;
+            self.nodes[node].count = 0;
+            pending.push(children);
+            pending.push(children + 1);
+        }
+    }
+
+    fn leaf(&self, first: usize, count: usize) -> Node {
+        let mut min = [f64::INFINITY; 3];
+        let mut max = [f64::NEG_INFINITY; 3];
+        for &triangle in &self.order[first..first + count] {
+            for corner in self.mesh.corners(triangle) {
+                for axis in 0..3 {
+                    min[axis] = min[axis].min(corner[axis]);
+                    max[axis] = max[axis].max(corner[axis]);
+                }
+            }
+        }
+        Node {
+            min,
+            max,
+            first,
+            count,
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Finds the closest point on the surface, if the manifold is not empty.
+    pub(crate) fn closest_point(&self, point: Vector) -> Option<ClosestHit> {
+        let mut best: Option<ClosestHit> = None;
+        let mut best_distance_squared = f64::INFINITY;
+        let mut pending = Vec::new();
+        if !self.is_empty() {
+            pending.push(0);
+        }
+        while let Some(node) = pending.pop() {
+            let node = &self.nodes[node];
+            if box_distance_squared(node, point) >= best_distance_squared {
+                continue;
+            }
+            if node.count > 0 {
+                for &triangle in &self.order[node.first..node.first + node.count] {
+                    let (position, barycentric) =
+                        closest_point_on_triangle(point, self.mesh.corners(triangle));
+                    let offset = sub(point, position);
+                    let distance_squared = dot(offset, offset);
+                    if distance_squared < best_distance_squared {
+                        best_distance_squared = distance_squared;
+                        best = Some(ClosestHit {
+                            triangle,
+                            position,
+                            barycentric,
+                            distance_squared,
+                        });
+                    }
+                }
+            } else {
+                // Visit the nearer child first, so it can prune the other one
+                let (near, far) = if box_distance_squared(&self.nodes[node.first], point)
+                    <= box_distance_squared(&self.nodes[node.first + 1], point)
+                {
+                    (node.first, node.first + 1)
+                } else {
+                    (node.first + 1, node.first)
+                };
+                pending.push(far);
+                pending.push(near);
+            }
+        }
+        best
+    }
+
+    /// Finds the closest pair of points between this surface and another one which are less
+    /// than `max_distance` apart, traversing both hierarchies at once.
+    pub(crate) fn closest_pair(
+        &self,
+        other: &SurfaceIndex,
+        max_distance: f64,
+    ) -> Option<ClosestPair> {
+        let mut best: Option<ClosestPair> = None;
+        let mut best_distance_squared = max_distance * max_distance;
+        let mut pending = Vec::new();
+        if !self.is_empty() && !other.is_empty() {
+            pending.push((0, 0));
+        }
+        while let Some((index, other_index)) = pending.pop() {
+            let (node, other_node) = (&self.nodes[index], &other.nodes[other_index]);
+            if boxes_distance_squared(node, other_node) >= best_distance_squared {
+                continue;
+            }
+            let is_leaf = node.count > 0;
+            let is_other_leaf = other_node.count > 0;
+            if is_leaf && is_other_leaf {
+                for &triangle in &self.order[node.first..node.first + node.count] {
+                    let corners = self.mesh.corners(triangle);
+                    let other_triangles =
+                        &other.order[other_node.first..other_node.first + other_node.count];
+                    for &other_triangle in other_triangles {
+                        let (point, other_point) = closest_points_of_triangles(
+                            corners,
+                            other.mesh.corners(other_triangle),
+                        );
+                        let offset = sub(point, other_point);
+                        let distance_squared = dot(offset, offset);
+                        if distance_squared < best_distance_squared {
+                            best_distance_squared = distance_squared;
+                            best = Some(ClosestPair {
+                                triangle,
+                                point,
+                                other_triangle,
+                                other_point,
+                                distance_squared,
+                            });
+                        }
+                    }
+                }
+            } else if is_other_leaf || (!is_leaf && node.size() >= other_node.size()) {
+                pending.push((node.first, other_index));
+                pending.push((node.first + 1, other_index));
+            } else {
+                pending.push((index, other_node.first));
+                pending.push((index, other_node.first + 1));
+            }
+        }
+        best
+    }
+
+    /// Finds all intersections of the ray with the surface up to `max_distance`, ordered by
+    /// distance. `direction` does not need to be normalized, distances are measured in
+    /// multiples of it.
+    pub(crate) fn ray_hits(
+        &self,
+        origin: Vector,
+        direction: Vector,
+        max_distance: f64,
+    ) -> Vec<RayHit> {
+        let mut hits = Vec::new();
+        let mut pending = Vec::new();
+        if !self.is_empty() {
+            pending.push(0);
+        }
+        while let Some(node) = pending.pop() {
+            let node = &self.nodes[node];
+            if !ray_hits_box(node, origin, direction, max_distance) {
+                continue;
+            }
+            if node.count > 0 {
+                for &triangle in &self.order[node.first..node.first + node.count] {
+                    let corners = self.mesh.corners(triangle);
+                    if let Some((distance, barycentric, is_entering)) =
+                        ray_triangle_intersection(origin, direction, corners)
+                    {
+                        if distance <= max_distance {
+                            hits.push(RayHit {
+                                triangle,
+                                distance,
+                                barycentric,
+                                is_entering,
+                            });
+                        }
+                    }
+                }
+            } else {
+                pending.push(node.first);
+                pending.push(node.first + 1);
+            }
+        }
+        hits.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+        hits
+    }
+
+    /// Checks whether the point lies inside the closed surface, by summing the crossings of
+    /// a ray. Rays grazing an edge or vertex are ambiguous and retried in another direction.
+    pub(crate) fn contains(&self, point: Vector) -> bool {
+        const DIRECTIONS: [Vector; 4] = [
+            [0.5773, 0.5774, 0.5775],
+            [-0.2673, 0.5345, 0.8018],
+            [0.8729, -0.2182, 0.4364],
+            [-0.4082, -0.8165, -0.4082],
+        ];
+        const GRAZING: f64 = 1e-9;
+
+        let mut winding = 0;
+        for direction in DIRECTIONS {
+            let hits = self.ray_hits(point, direction, f64::INFINITY);
+            let is_grazing = hits
+                .iter()
+                .any(|hit| hit.barycentric.iter().any(|&weight| weight < GRAZING));
+            winding = hits
+                .iter()
+                .map(|hit| if hit.is_entering { -1 } else { 1 })
+                .sum::<i32>();
+            if !is_grazing {
+                break;
+            }
+        }
+        winding > 0
+    }
+}
+
+fn box_distance_squared(node: &Node, point: Vector) -> f64 {
+    (0..3)
+        .map(|axis| {
+            let outside = (node.min[axis] - point[axis]).max(point[axis] - node.max[axis]);
+            outside.max(0.0).powi(2)
+        })
+        .sum()
+}
+
+fn boxes_distance_squared(node: &Node, other: &Node) -> f64 {
+    (0..3)
+        .map(|axis| {
+            let gap = (node.min[axis] - other.max[axis]).max(other.min[axis] - node.max[axis]);
+            gap.max(0.0).powi(2)
+        })
+        .sum()
+}
+
+fn ray_hits_box(node: &Node, origin: Vector, direction: Vector, max_distance: f64) -> bool {
+    let mut near = 0.0f64;
+    let mut far = max_distance;
+    for axis in 0..3 {
+        if direction[axis] == 0.0 {
+            if origin[axis] < node.min[axis] || origin[axis] > node.max[axis] {
+                return false;
+            }
+            continue;
+        }
+        let to_min = (node.min[axis] - origin[axis]) / direction[axis];
+        let to_max = (node.max[axis] - origin[axis]) / direction[axis];
+        near = near.max(to_min.min(to_max));
+        far = far.min(to_min.max(to_max));
+    }
+    near <= far
+}
+
+/// Intersects a ray with a triangle using the Möller–Trumbore algorithm.
+///
+/// Returns the distance in multiples of `direction`, the barycentric coordinates of the hit
+/// and whether the ray enters through the front face.
+fn ray_triangle_intersection(
+    origin: Vector,
+    direction: Vector,
+    [a, b, c]: [Vector; 3],
+) -> Option<(f64, Vector, bool)> {
+    let edge_ab = sub(b, a);
+    let edge_ac = sub(c, a);
+    let p = cross(direction, edge_ac);
+    let determinant = dot(edge_ab, p);
+    if determinant == 0.0 {
+        return None;
+    }
+    let inverse_determinant = 1.0 / determinant;
+    let to_origin = sub(origin, a);
+    let u = dot(to_origin, p) * inverse_determinant;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let q = cross(to_origin, edge_ab);
+    let v = dot(direction, q) * inverse_determinant;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let distance = dot(edge_ac, q) * inverse_determinant;
+    if distance < 0.0 {
+        return None;
+    }
+    // Counter-clockwise triangles face outwards, so their normal opposes an entering ray
+    Some((distance, [1.0 - u - v, u, v], determinant > 0.0))
+}
+
+/// Finds the closest point on a triangle and its barycentric coordinates, following
+/// Ericson's Real-Time Collision Detection.
+pub(crate) fn closest_point_on_triangle(point: Vector, [a, b, c]: [Vector; 3]) -> (Vector, Vector) {
+    let ab = sub(b, a);
+    let ac = sub(c, a);
+    let ap = sub(point, a);
+    let d1 = dot(ab, ap);
+    let d2 = dot(ac, ap);
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return (a, [1.0, 0.0, 0.0]);
+    }
+
+    let bp = sub(point, b);
+    let d3 = dot(ab, bp);
+    let d4 = dot(ac, bp);
+    if d3 >= 0.0 && d4 <= d3 {
+        return (b, [0.0, 1.0, 0.0]);
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        let v = d1 / (d1 - d3);
+        return (interpolate([a, b, c], [1.0 - v, v, 0.0]), [1.0 - v, v, 0.0]);
+    }
+
+    let cp = sub(point, c);
+    let d5 = dot(ab, cp);
+    let d6 = dot(ac, cp);
+    if d6 >= 0.0 && d5 <= d6 {
+        return (c, [0.0, 0.0, 1.0]);
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        let w = d2 / (d2 - d6);
+        return (interpolate([a, b, c], [1.0 - w, 0.0, w]), [1.0 - w, 0.0, w]);
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return (interpolate([a, b, c], [0.0, 1.0 - w, w]), [0.0, 1.0 - w, w]);
+    }
+
+    let denominator = 1.0 / (va + vb + vc);
+    let v = vb * denominator;
+    let w = vc * denominator;
+    let barycentric = [1.0 - v - w, v, w];
+    (interpolate([a, b, c], barycentric), barycentric)
+}
+
+pub(crate) fn interpolate(corners: [Vector; 3], barycentric: Vector) -> Vector {
+    [0, 1, 2].map(|axis| {
+        corners[0][axis] * barycentric[0]
+            + corners[1][axis] * barycentric[1]
+            + corners[2][axis] * barycentric[2]
+    })
+}
+
+/// Finds the closest points between two triangles. Intersecting triangles are zero apart at
+/// a point where an edge of one pierces the other.
+fn closest_points_of_triangles(first: [Vector; 3], second: [Vector; 3]) -> (Vector, Vector) {
+    for (triangle, other) in [(first, second), (second, first)] {
+        for corner in 0..3 {
+            let start = triangle[corner];
+            let end = triangle[(corner + 1) % 3];
+            if let Some((distance, _, _)) = ray_triangle_intersection(start, sub(end, start), other)
+            {
+                if distance <= 1.0 {
+                    let point = add(start, scale(sub(end, start), distance));
+                    return (point, point);
+                }
+            }
+        }
+    }
+
+    let mut best = (first[0], second[0]);
+    let mut best_distance_squared = f64::INFINITY;
+    let mut consider = |point: Vector, other_point: Vector| {
+        let offset = sub(point, other_point);
+        let distance_squared = dot(offset, offset);
+        if distance_squared < best_distance_squared {
+            best_distance_squared = distance_squared;
+            best = (point, other_point);
+        }
+    };
+    for corner in first {
+        consider(corner, closest_point_on_triangle(corner, second).0);
+    }
+    for corner in second {
+        consider(closest_point_on_triangle(corner, first).0, corner);
+    }
+    for corner in 0..3 {
+        for other_corner in 0..3 {
+            let (point, other_point) = closest_points_of_segments(
+                [first[corner], first[(corner + 1) % 3]],
+                [second[other_corner], second[(other_corner + 1) % 3]],
+            );
+            consider(point, other_point);
+        }
+    }
+    best
+}
+
+/// Finds the closest points between two segments, following Ericson's Real-Time Collision
+/// Detection.
+fn closest_points_of_segments([p1, q1]: [Vector; 2], [p2, q2]: [Vector; 2]) -> (Vector, Vector) {
+    let d1 = sub(q1, p1);
+    let d2 = sub(q2, p2);
+    let r = sub(p1, p2);
+    let a = dot(d1, d1);
+    let e = dot(d2, d2);
+    let f = dot(d2, r);
+
+    let (s, t) = if a <= f64::EPSILON && e <= f64::EPSILON {
+        (0.0, 0.0)
+    } else if a <= f64::EPSILON {
+        (0.0, (f / e).clamp(0.0, 1.0))
+    } else {
+        let c = dot(d1, r);
+        if e <= f64::EPSILON {
+            ((-c / a).clamp(0.0, 1.0), 0.0)
+        } else {
+            let b = dot(d1, d2);
+            let denominator = a * e - b * b;
+            let s = if denominator > 0.0 {
+                ((b * f - c * e) / denominator).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let t = (b * s + f) / e;
+            if t < 0.0 {
+                ((-c / a).clamp(0.0, 1.0), 0.0)
+            } else if t > 1.0 {
+                (((b - c) / a).clamp(0.0, 1.0), 1.0)
+            } else {
+                (s, t)
+            }
+        }
+    };
+    (add(p1, scale(d1, s)), add(p2, scale(d2, t)))
+}
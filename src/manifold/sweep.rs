@@ -0,0 +1,293 @@
+use crate::error::{check_error, Error};
+use crate::manifold::geometry::{add, cross, dot, length, orthonormal_basis, scale, sub, Vector};
+use crate::manifold::Manifold;
+use crate::types::{Point3, PositiveF64, PositiveI32, Vec2};
+use crate::{CrossSection, Polygons};
+use manifold3d_sys::{manifold_alloc_manifold, manifold_warp, ManifoldVec3};
+use std::os::raw::c_void;
+use thiserror::Error;
+
+/// Errors that can occur while sweeping a profile along a path.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum SweepError {
+    #[error("the path has {actual} points, but at least {minimum} are required")]
+    TooFewPoints { minimum: usize, actual: usize },
+    #[error("the path has {path} points, but {actual} {option} were given")]
+    OptionLengthMismatch {
+        option: &'static str,
+        path: usize,
+        actual: usize,
+    },
+    #[error("the path segment starting at point {0} has zero length")]
+    DegenerateSegment(usize),
+    #[error("the swept profile is not a valid manifold: {0:?}")]
+    InvalidManifold(Error),
+}
+
+/// Options controlling [`Manifold::sweep`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SweepOptions {
+    /// Whether the last point of the path connects back to the first one, producing a ring
+    /// without end caps instead of a tube closed by caps at both ends.
+    pub closed: bool,
+    /// The scale of the profile along its X and Y axes at every point of the path, or empty
+    /// to keep the profile at its size.
+    pub scales: Vec<Vec2>,
+    /// The counter-clockwise rotation of the profile in degrees at every point of the path,
+    /// or empty to not twist it.
+    pub twist_degrees: Vec<f64>,
+}
+
+/// The placement of the profile at one point of the path.
+#[derive(Debug, Clone, Copy)]
+struct Ring {
+    origin: Vector,
+    /// The direction of the X axis of the profile.
+    normal: Vector,
+    /// The direction of the Y axis of the profile.
+    binormal: Vector,
+    scale: Vec2,
+    twist_radians: f64,
+}
+
+impl Ring {
+    fn place(&self, x: f64, y: f64) -> Vector {
+        let (x, y) = (x * self.scale.x, y * self.scale.y);
+        let (sin, cos) = self.twist_radians.sin_cos();
+        let offset = add(
+            scale(self.normal, x * cos - y * sin),
+            scale(self.binormal, x * sin + y * cos),
+        );
+        add(self.origin, offset)
+    }
+}
+
+impl Manifold {
+    /// Sweeps the profile in the X-Y plane along the path, keeping it perpendicular to the
+    /// path with its origin on it.
+    ///
+    /// The profile is oriented by rotation minimizing frames, so it does not spin around the
+    /// path beyond the twist given in the options. The X axis of the profile starts out
+    /// perpendicular to the first segment of the path, and the path runs along the Z axis
+    /// of the profile, like in [`Polygons::extrude`]. At every inner point the profile is
+    /// perpendicular to the mean direction of its two segments, so sharp corners pinch the
+    /// sweep and paths should be sampled finely where they bend.
+    ///
+    /// Open paths are closed by caps of the profile at both ends. Closed paths produce a ring
+    /// whose frames are twisted evenly along the path so that the profile meets itself
+    /// again, which requires the scale and twist of the first point to be the ones the path
+    /// returns to. The profile must be oriented like for [`Polygons::extrude`], and the
+    /// path must be smooth enough relative to the size of the profile for the sweep not
+    /// to intersect itself.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::manifold::SweepOptions;
+    /// use manifold3d::types::{Point2, Point3};
+    /// use manifold3d::{Manifold, Polygons, SimplePolygon};
+    ///
+    /// let square = Polygons::from_simple_polygons(vec![SimplePolygon::new_from_points(vec![
+    ///     Point2::new(-1.0, -1.0),
+    ///     Point2::new(1.0, -1.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(-1.0, 1.0),
+    /// ])]);
+    /// let path = [Point3::new(0.0, 0.0, 0.0), Point3::new(5.0, 0.0, 0.0)];
+    ///
+    /// let beam = Manifold::sweep(&square, &path, &SweepOptions::default()).unwrap();
+    /// assert!((beam.volume() - 20.0).abs() < 1e-9);
+    /// assert!((beam.bounding_box().dimensions().x - 5.0).abs() < 1e-9);
+    /// ```
+    pub fn sweep(
+        profile: &Polygons,
+        path: &[Point3],
+        options: &SweepOptions,
+    ) -> Result<Manifold, SweepError> {
+        let rings = rings(path, options)?;
+        if !options.closed {
+            return sweep_open(profile, &rings);
+        }
+        // The two halves share their end rings exactly, so their caps cancel in the union
+        let mut rings = rings;
+        rings.push(rings[0]);
+        let middle = rings.len() / 2;
+        let first = sweep_open(profile, &rings[..=middle])?;
+        let second = sweep_open(profile, &rings[middle..])?;
+        Ok(first.union(&second))
+    }
+}
+
+impl CrossSection {
+    /// Sweeps the cross-section along the path, see [`Manifold::sweep`].
+    pub fn sweep(&self, path: &[Point3], options: &SweepOptions) -> Result<Manifold, SweepError> {
+        Manifold::sweep(&self.to_polygons(), path, options)
+    }
+}
+
+/// Validates the path and options and returns the placement of the profile at every point
+/// of the path.
+fn rings(path: &[Point3], options: &SweepOptions) -> Result<Vec<Ring>, SweepError> {
+    let count = path.len();
+    let minimum = if options.closed { 3 } else { 2 };
+    if count < minimum {
+        return Err(SweepError::TooFewPoints {
+            minimum,
+            actual: count,
+        });
+    }
+    for (option, actual) in [
+        ("scales", options.scales.len()),
+        ("twist angles", options.twist_degrees.len()),
+    ] {
+        if actual != 0 && actual != count {
+            return Err(SweepError::OptionLengthMismatch {
+                option,
+                path: count,
+                actual,
+            });
+        }
+    }
+
+    let points = path.iter().map(|p| [p.x, p.y, p.z]).collect::<Vec<_>>();
+    let segment_count = if options.closed { count } else { count - 1 };
+    let mut directions = Vec::with_capacity(segment_count);
+    let mut lengths = Vec::with_capacity(segment_count);
+    for i in 0..segment_count {
+        let along = sub(points[(i + 1) % count], points[i]);
+        let segment_length = length(along);
+        if segment_length == 0.0 {
+            return Err(SweepError::DegenerateSegment(i));
+        }
+        directions.push(scale(along, 1.0 / segment_length));
+        lengths.push(segment_length);
+    }
+
+    let tangents = (0..count)
+        .map(|i| {
+            let next = directions[i.min(segment_count - 1)];
+            let previous = match (i, options.closed) {
+                (0, true) => directions[segment_count - 1],
+                (0, false) => next,
+                _ => directions[i - 1],
+            };
+            let mean = add(previous, next);
+            let mean_length = length(mean);
+            // The path turns back on itself
+            if mean_length < 1e-9 {
+                next
+            } else {
+                scale(mean, 1.0 / mean_length)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Rotation minimizing frames by double reflection, see Wang et al., "Computation of
+    // rotation minimizing frames", 2008
+    let mut normals = vec![orthonormal_basis(tangents[0]).0];
+    for i in 0..segment_count {
+        let next = (i + 1) % count;
+        let reflect = |vector: Vector, axis: Vector| {
+            let axis_length = dot(axis, axis);
+            if axis_length < 1e-18 {
+                vector
+            } else {
+                sub(vector, scale(axis, 2.0 * dot(axis, vector) / axis_length))
+            }
+        };
+        let along = sub(points[next], points[i]);
+        let reflected_normal = reflect(normals[i], along);
+        let reflected_tangent = reflect(tangents[i], along);
+        normals.push(reflect(
+            reflected_normal,
+            sub(tangents[next], reflected_tangent),
+        ));
+    }
+
+    let mut corrections = vec![0.0; count];
+    if options.closed {
+        // Spread the rotation between the first frame and the one carried around the path
+        let first = normals[0];
+        let last = normals[count];
+        let mismatch = dot(cross(first, last), tangents[0]).atan2(dot(first, last));
+        let total_length = lengths.iter().sum::<f64>();
+        let mut travelled = 0.0;
+        for i in 1..count {
+            travelled += lengths[i - 1];
+            corrections[i] = -mismatch * travelled / total_length;
+        }
+    }
+
+    Ok((0..count)
+        .map(|i| {
+            let tangent = tangents[i];
+            // Keep the normal exactly perpendicular to the averaged tangent
+            let normal = sub(normals[i], scale(tangent, dot(normals[i], tangent)));
+            let normal = scale(normal, 1.0 / length(normal));
+            Ring {
+                origin: points[i],
+                normal,
+                binormal: cross(tangent, normal),
+                scale: options
+                    .scales
+                    .get(i)
+                    .copied()
+                    .unwrap_or(Vec2 { x: 1.0, y: 1.0 }),
+                twist_radians: options
+                    .twist_degrees
+                    .get(i)
+                    .copied()
+                    .unwrap_or(0.0)
+                    .to_radians()
+                    + corrections[i],
+            }
+        })
+        .collect())
+}
+
+/// Sweeps the profile along the rings with caps at both ends, by bending an extrusion of
+/// the profile with one ring of vertices per ring.
+fn sweep_open(profile: &Polygons, rings: &[Ring]) -> Result<Manifold, SweepError> {
+    // An extrusion with n divisions has n + 2 rings of vertices
+    let division_count = rings.len().saturating_sub(2).max(1);
+    let extrusion = profile
+        .extrude(
+            PositiveF64::new(1.0).unwrap(),
+            PositiveI32::new(division_count as i32).unwrap(),
+            0.0,
+            None::<Vec2>,
+        )
+        .map_err(SweepError::InvalidManifold)?;
+
+    let ctx = SweepCtx { rings };
+    let manifold_ptr = unsafe {
+        manifold_warp(
+            manifold_alloc_manifold() as *mut c_void,
+            extrusion.ptr(),
+            Some(place_vertex),
+            &raw const ctx as *mut c_void,
+        )
+    };
+    check_error(Manifold::from_ptr(manifold_ptr)).map_err(SweepError::InvalidManifold)
+}
+
+struct SweepCtx<'a> {
+    rings: &'a [Ring],
+}
+
+unsafe extern "C" fn place_vertex(x: f64, y: f64, z: f64, ctx: *mut c_void) -> ManifoldVec3 {
+    let rings = (*(ctx as *const SweepCtx)).rings;
+    let last = rings.len() - 1;
+    let position = z * last as f64;
+    let nearest = position.round();
+    let [x, y, z] = if (position - nearest).abs() < 1e-6 {
+        rings[(nearest as usize).min(last)].place(x, y)
+    } else {
+        // A vertex between two rings, only present if there are just two rings
+        let index = (position.floor() as usize).min(last - 1);
+        let fraction = position - index as f64;
+        let start = rings[index].place(x, y);
+        let end = rings[index + 1].place(x, y);
+        add(start, scale(sub(end, start), fraction))
+    };
+    ManifoldVec3 { x, y, z }
+}
@@ -0,0 +1,211 @@
+use crate::manifold::geometry::{add, cross, length, scale, sub, Vector};
+use crate::manifold::surface_index::SurfaceIndex;
+use crate::manifold::Manifold;
+use crate::types::{NonNegativeI32, PositiveF64};
+use manifold3d_sys::{manifold_alloc_manifold, manifold_set_properties, ManifoldVec3};
+use std::collections::HashMap;
+use std::os::raw::c_void;
+
+/// The number of histogram bins below twice the minimum thickness.
+const HISTOGRAM_BIN_COUNT: usize = 8;
+
+/// The result of [`Manifold::wall_thickness`].
+pub struct WallThickness {
+    /// The analyzed manifold with the thickness at every vertex written into the property
+    /// channel.
+    pub manifold: Manifold,
+    /// The smallest measured thickness.
+    pub minimum: f64,
+    /// The sorted face IDs of all faces with a triangle thinner than the minimum thickness.
+    pub thin_face_ids: Vec<u64>,
+    /// The surface area by thickness. The bins cover a quarter of the minimum thickness each,
+    /// up to twice the minimum thickness, and the last bin covers everything thicker.
+    pub histogram: Vec<ThicknessBin>,
+}
+
+/// A bin of the histogram of a [`WallThickness`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThicknessBin {
+    /// The inclusive lower bound of the thickness.
+    pub min: f64,
+    /// The exclusive upper bound of the thickness.
+    pub max: f64,
+    /// The number of triangles whose thickness falls into the bin.
+    pub triangle_count: usize,
+    /// The total area of these triangles.
+    pub area: f64,
+}
+
+/// Maps the position of every vertex to its thickness for the property callback.
+struct ThicknessCtx {
+    thickness_of_position: HashMap<[u64; 3], f64>,
+    old_properties_per_vertex_count: usize,
+    new_properties_per_vertex_count: usize,
+    property_index: usize,
+}
+
+impl Manifold {
+    /// Measures the wall thickness of the manifold to find regions which are too thin to be
+    /// produced, e.g. by the nozzle of a 3D printer.
+    ///
+    /// The thickness at a sample point is the distance a ray, cast inward against a surface
+    /// normal, travels until it leaves the manifold again. Every triangle is sampled at its
+    /// centroid against its normal. Every vertex is sampled against the normals of all
+    /// triangles around it, keeping the smallest thickness, so the vertices at the rim of
+    /// a thin wall measure the wall rather than the diagonal through it. Thicknesses are
+    /// capped at the diagonal of the bounding box.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_thickness`: The thickness below which triangles count as thin.
+    /// * `property_index`: The index of the property channel in which the thickness at every
+    ///   vertex is stored. Like for [`Manifold::calculate_curvature`], the property set
+    ///   automatically expands to include the channel.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::{NonNegativeI32, PositiveF64};
+    /// use manifold3d::Manifold;
+    ///
+    /// let plate = Manifold::new_cuboid(
+    ///     PositiveF64::new(10.0).unwrap(),
+    ///     PositiveF64::new(10.0).unwrap(),
+    ///     PositiveF64::new(0.4).unwrap(),
+    ///     false,
+    /// );
+    /// let thickness = plate.wall_thickness(
+    ///     PositiveF64::new(0.8).unwrap(),
+    ///     NonNegativeI32::new(0).unwrap(),
+    /// );
+    ///
+    /// assert!((thickness.minimum - 0.4).abs() < 1e-9);
+    /// assert_eq!(thickness.manifold.properties_per_vertex_count(), 1);
+    /// // The top and bottom are too thin, the sides span the whole plate
+    /// assert_eq!(thickness.histogram[2].triangle_count, 4);
+    /// ```
+    pub fn wall_thickness(
+        &self,
+        min_thickness: PositiveF64,
+        property_index: impl Into<NonNegativeI32>,
+    ) -> WallThickness {
+        let min_thickness = min_thickness.get();
+        let property_index = property_index.into().get() as usize;
+        let index = self.surface_index();
+        let mesh = &index.mesh;
+        let max_thickness = {
+            let bounding_box = self.bounding_box();
+            let diagonal = bounding_box.max_point() - bounding_box.min_point();
+            (diagonal.x * diagonal.x + diagonal.y * diagonal.y + diagonal.z * diagonal.z).sqrt()
+        };
+        // Rays start on the surface, so hits this close to their origin are the surface
+        // around the start
+        let threshold = self.epsilon().max(max_thickness * 1e-9);
+
+        let mut vertex_thickness = vec![max_thickness; mesh.positions.len()];
+        let mut histogram = (0..=HISTOGRAM_BIN_COUNT)
+            .map(|bin| ThicknessBin {
+                min: min_thickness * bin as f64 / 4.0,
+                max: if bin < HISTOGRAM_BIN_COUNT {
+                    min_thickness * (bin + 1) as f64 / 4.0
+                } else {
+                    f64::INFINITY
+                },
+                triangle_count: 0,
+                area: 0.0,
+            })
+            .collect::<Vec<_>>();
+        let mut thin_face_ids = Vec::new();
+        let mut minimum = f64::INFINITY;
+
+        for (triangle, vertices) in mesh.triangles.iter().enumerate() {
+            let [a, b, c] = mesh.corners(triangle);
+            let normal = cross(sub(b, a), sub(c, a));
+            let double_area = length(normal);
+            if double_area == 0.0 {
+                continue;
+            }
+            let inward = scale(normal, -1.0 / double_area);
+            let measure =
+                |origin| thickness_along(&index, origin, inward, threshold).min(max_thickness);
+
+            let centroid = scale(add(a, add(b, c)), 1.0 / 3.0);
+            let thickness = measure(centroid);
+            let bin = ((thickness / min_thickness * 4.0) as usize).min(HISTOGRAM_BIN_COUNT);
+            histogram[bin].triangle_count += 1;
+            histogram[bin].area += double_area / 2.0;
+            if thickness < min_thickness {
+                thin_face_ids.push(index.face_ids[triangle]);
+            }
+            minimum = minimum.min(thickness);
+
+            for &vertex in vertices {
+                let thickness = measure(mesh.positions[vertex]);
+                vertex_thickness[vertex] = vertex_thickness[vertex].min(thickness);
+                minimum = minimum.min(thickness);
+            }
+        }
+        thin_face_ids.sort_unstable();
+        thin_face_ids.dedup();
+
+        let old_properties_per_vertex_count = self.properties_per_vertex_count();
+        let ctx = ThicknessCtx {
+            thickness_of_position: mesh
+                .positions
+                .iter()
+                .zip(vertex_thickness)
+                .map(|(position, thickness)| (position.map(f64::to_bits), thickness))
+                .collect(),
+            old_properties_per_vertex_count,
+            new_properties_per_vertex_count: old_properties_per_vertex_count
+                .max(property_index + 1),
+            property_index,
+        };
+        let manifold_ptr = unsafe {
+            manifold_set_properties(
+                manifold_alloc_manifold() as *mut c_void,
+                self.0,
+                ctx.new_properties_per_vertex_count as i32,
+                Some(write_thickness),
+                &raw const ctx as *mut c_void,
+            )
+        };
+
+        WallThickness {
+            manifold: Manifold::from_ptr(manifold_ptr),
+            minimum: if minimum.is_finite() { minimum } else { 0.0 },
+            thin_face_ids,
+            histogram,
+        }
+    }
+}
+
+/// Returns the distance from the origin on the surface along the direction to the point
+/// where the ray leaves the manifold, or infinity if it does not.
+fn thickness_along(index: &SurfaceIndex, origin: Vector, direction: Vector, threshold: f64) -> f64 {
+    index
+        .ray_hits(origin, direction, f64::INFINITY)
+        .into_iter()
+        .find(|hit| !hit.is_entering && hit.distance > threshold)
+        .map_or(f64::INFINITY, |hit| hit.distance)
+}
+
+unsafe extern "C" fn write_thickness(
+    new_properties: *mut f64,
+    position: ManifoldVec3,
+    old_properties: *const f64,
+    ctx: *mut c_void,
+) {
+    let ctx = &*(ctx as *const ThicknessCtx);
+    let new_properties =
+        std::slice::from_raw_parts_mut(new_properties, ctx.new_properties_per_vertex_count);
+    if ctx.old_properties_per_vertex_count > 0 {
+        let old_properties =
+            std::slice::from_raw_parts(old_properties, ctx.old_properties_per_vertex_count);
+        new_properties[..old_properties.len()].copy_from_slice(old_properties);
+    }
+    new_properties[ctx.property_index] = ctx
+        .thickness_of_position
+        .get(&[position.x, position.y, position.z].map(f64::to_bits))
+        .copied()
+        .unwrap_or(f64::NAN);
+}
@@ -1,7 +1,8 @@
 use crate::Manifold;
 use manifold3d_sys::{
-    manifold_alloc_manifold, manifold_compose, manifold_manifold_vec_get,
-    manifold_manifold_vec_length, ManifoldManifoldVec,
+    manifold_alloc_manifold, manifold_alloc_manifold_vec, manifold_compose,
+    manifold_delete_manifold_vec, manifold_manifold_vec, manifold_manifold_vec_get,
+    manifold_manifold_vec_length, manifold_manifold_vec_set, ManifoldManifoldVec,
 };
 use std::os::raw::c_void;
 
@@ -12,6 +13,20 @@ impl ManifoldVec {
         ManifoldVec(ptr)
     }
 
+    /// Creates a vector holding copies of the manifolds.
+    pub fn from_manifolds(manifolds: &[Manifold]) -> ManifoldVec {
+        let manifold_vec_ptr = unsafe {
+            manifold_manifold_vec(
+                manifold_alloc_manifold_vec() as *mut c_void,
+                manifolds.len(),
+            )
+        };
+        for (index, manifold) in manifolds.iter().enumerate() {
+            unsafe { manifold_manifold_vec_set(manifold_vec_ptr, index, manifold.ptr()) };
+        }
+        ManifoldVec::from_ptr(manifold_vec_ptr)
+    }
+
     pub fn compose(&self) -> Manifold {
         let manifold_ptr =
             unsafe { manifold_compose(manifold_alloc_manifold() as *mut c_void, self.0) };
@@ -43,3 +58,11 @@ impl ManifoldVec {
         manifolds
     }
 }
+
+impl Drop for ManifoldVec {
+    fn drop(&mut self) {
+        unsafe {
+            manifold_delete_manifold_vec(self.0);
+        }
+    }
+}
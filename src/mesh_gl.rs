@@ -1,39 +1,384 @@
+use crate::manifold::geometry::{dihedral_edges, merged_vertices};
 use crate::manifold::Manifold;
-use crate::{check_error, Error, HalfEdgeIndex};
+use crate::types::NormalizedAngle;
+use crate::{check_error, Error, HalfEdgeIndex, Smoothness};
 use manifold3d_sys::{
-    manifold_alloc_manifold, manifold_alloc_meshgl, manifold_delete_meshgl, manifold_meshgl_copy,
-    manifold_meshgl_face_id_length, manifold_meshgl_merge, manifold_meshgl_merge_length,
-    manifold_meshgl_num_prop, manifold_meshgl_num_tri, manifold_meshgl_num_vert,
-    manifold_meshgl_run_index_length, manifold_meshgl_run_original_id_length,
+    manifold_alloc_manifold, manifold_alloc_meshgl, manifold_delete_meshgl, manifold_meshgl,
+    manifold_meshgl_copy, manifold_meshgl_face_id, manifold_meshgl_face_id_length,
+    manifold_meshgl_halfedge_tangent, manifold_meshgl_merge, manifold_meshgl_merge_from_vert,
+    manifold_meshgl_merge_length, manifold_meshgl_merge_to_vert, manifold_meshgl_num_prop,
+    manifold_meshgl_num_tri, manifold_meshgl_num_vert, manifold_meshgl_run_index,
+    manifold_meshgl_run_index_length, manifold_meshgl_run_original_id,
+    manifold_meshgl_run_original_id_length, manifold_meshgl_run_transform,
     manifold_meshgl_run_transform_length, manifold_meshgl_tangent_length,
-    manifold_meshgl_tri_length, manifold_meshgl_vert_properties,
-    manifold_meshgl_vert_properties_length, manifold_smooth, ManifoldMeshGL,
+    manifold_meshgl_tri_length, manifold_meshgl_tri_verts, manifold_meshgl_vert_properties,
+    manifold_meshgl_vert_properties_length, manifold_meshgl_w_tangents, manifold_smooth,
+    ManifoldMeshGL,
 };
-use std::alloc::{alloc, Layout};
 use std::os::raw::c_void;
 
-pub struct MeshGL(*mut ManifoldMeshGL);
+/// Implements the constructors, buffer accessors, [`Clone`] and [`Drop`] which [`MeshGL`] and
+/// [`crate::MeshGL64`] share, from the C functions of their precision, together with the
+/// struct holding copies of all their buffers.
+macro_rules! impl_mesh_gl {
+    (
+        $mesh:ident($raw:ty), $float:ty, $index:ty,
+        buffers: $buffers:ident,
+        alloc: $alloc:ident,
+        delete: $delete:ident,
+        copy: $copy:ident,
+        new: $new:ident,
+        new_with_tangents: $new_with_tangents:ident,
+        merge: $merge:ident,
+        vertex_properties: $vertex_properties:ident / $vertex_properties_length:ident,
+        vertex_indices: $vertex_indices:ident / $vertex_indices_length:ident,
+        merge_vectors: $merge_from:ident, $merge_to:ident / $merge_length:ident,
+        run_indices: $run_indices:ident / $run_indices_length:ident,
+        run_original_ids: $run_original_ids:ident / $run_original_ids_length:ident,
+        run_transforms: $run_transforms:ident / $run_transforms_length:ident,
+        face_ids: $face_ids:ident / $face_ids_length:ident,
+        halfedge_tangents: $halfedge_tangents:ident / $halfedge_tangents_length:ident $(,)?
+    ) => {
+        #[doc = concat!("Copies of all buffers of a [`", stringify!($mesh), "`], see [`")]
+        #[doc = concat!(stringify!($mesh), "::buffers`].")]
+        #[derive(Debug, Clone, PartialEq, Default)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $buffers {
+            pub properties_per_vertex: usize,
+            pub vertex_properties: Vec<$float>,
+            pub vertex_indices: Vec<$index>,
+            pub merge_from_vertex_indices: Vec<$index>,
+            pub merge_to_vertex_indices: Vec<$index>,
+            pub run_indices: Vec<$index>,
+            pub run_original_ids: Vec<u32>,
+            pub run_transforms: Vec<$float>,
+            pub face_ids: Vec<$index>,
+            pub halfedge_tangents: Vec<$float>,
+        }
 
-impl MeshGL {
-    pub(crate) fn from_ptr(ptr: *mut ManifoldMeshGL) -> MeshGL {
-        MeshGL(ptr)
-    }
+        impl $mesh {
+            /// Creates a mesh from a flat GL-style interleaved list of vertex properties and a
+            /// list of triangles, each given by three vertex indices in counter-clockwise order.
+            ///
+            /// The first three properties of every vertex are its position.
+            pub fn new(
+                vertex_properties: &[$float],
+                properties_per_vertex: usize,
+                vertex_indices: &[$index],
+            ) -> Result<$mesh, Error> {
+                $crate::mesh_gl::validate_buffers(
+                    vertex_properties.len(),
+                    properties_per_vertex,
+                    vertex_indices,
+                )?;
+                let mesh_gl_ptr = unsafe {
+                    $new(
+                        $alloc() as *mut c_void,
+                        vertex_properties.as_ptr() as *mut $float,
+                        vertex_properties.len() / properties_per_vertex,
+                        properties_per_vertex,
+                        vertex_indices.as_ptr() as *mut $index,
+                        vertex_indices.len() / 3,
+                    )
+                };
+                Ok($mesh(mesh_gl_ptr))
+            }
 
-    pub(crate) fn ptr(&self) -> *mut ManifoldMeshGL {
-        self.0
-    }
+            #[doc = concat!("Creates a mesh like [`", stringify!($mesh), "::new`], additionally")]
+            /// providing the tangents used for smoothing.
+            ///
+            /// # Arguments
+            ///
+            /// * `halfedge_tangents`: Four values per halfedge, three for the tangent direction
+            ///   and one for its weight. The halfedges are ordered like the vertex indices.
+            pub fn new_with_tangents(
+                vertex_properties: &[$float],
+                properties_per_vertex: usize,
+                vertex_indices: &[$index],
+                halfedge_tangents: &[$float],
+            ) -> Result<$mesh, Error> {
+                $crate::mesh_gl::validate_buffers(
+                    vertex_properties.len(),
+                    properties_per_vertex,
+                    vertex_indices,
+                )?;
+                if halfedge_tangents.len() != vertex_indices.len() * 4 {
+                    return Err(Error::TangentsWrongLength);
+                }
+                let mesh_gl_ptr = unsafe {
+                    $new_with_tangents(
+                        $alloc() as *mut c_void,
+                        vertex_properties.as_ptr() as *mut $float,
+                        vertex_properties.len() / properties_per_vertex,
+                        properties_per_vertex,
+                        vertex_indices.as_ptr() as *mut $index,
+                        vertex_indices.len() / 3,
+                        halfedge_tangents.as_ptr() as *mut $float,
+                    )
+                };
+                Ok($mesh(mesh_gl_ptr))
+            }
+
+            pub(crate) fn from_ptr(ptr: *mut $raw) -> $mesh {
+                $mesh(ptr)
+            }
+
+            pub(crate) fn ptr(&self) -> *mut $raw {
+                self.0
+            }
+
+            pub fn merge(&self) -> Option<$mesh> {
+                let duplicate_ptr = unsafe { $alloc() };
+                let returned_ptr = unsafe { $merge(duplicate_ptr as *mut c_void, self.0) };
+
+                // If the pointer to the duplicate_ptr was returned it means the operation was
+                // successful
+                if duplicate_ptr == returned_ptr {
+                    return Some($mesh(duplicate_ptr));
+                }
+                None
+            }
+
+            /// Returns the length of the flat GL-style interleaved list of all vertex
+            /// properties.
+            pub fn vertex_property_count(&self) -> usize {
+                unsafe { $vertex_properties_length(self.0) }
+            }
+
+            pub fn vertex_index_count(&self) -> usize {
+                unsafe { $vertex_indices_length(self.0) }
+            }
+
+            pub fn mesh_merge_count(&self) -> usize {
+                unsafe { $merge_length(self.0) }
+            }
+
+            pub fn run_index_count(&self) -> usize {
+                unsafe { $run_indices_length(self.0) }
+            }
+
+            pub fn run_original_id_count(&self) -> usize {
+                unsafe { $run_original_ids_length(self.0) }
+            }
+
+            pub fn run_transform_count(&self) -> usize {
+                unsafe { $run_transforms_length(self.0) }
+            }
+
+            pub fn face_id_count(&self) -> usize {
+                unsafe { $face_ids_length(self.0) }
+            }
+
+            pub fn tangent_count(&self) -> usize {
+                unsafe { $halfedge_tangents_length(self.0) }
+            }
+
+            /// Returns a copy of the flat GL-style interleaved list of all vertex properties.
+            pub fn vertex_properties(&self) -> Vec<$float> {
+                self.copy_buffer(self.vertex_property_count(), $vertex_properties)
+            }
+
+            /// Returns a copy of the vertex indices, three per triangle.
+            pub fn vertex_indices(&self) -> Vec<$index> {
+                self.copy_buffer(self.vertex_index_count(), $vertex_indices)
+            }
+
+            /// Returns a copy of the vertices which are merged into the vertices at the same
+            #[doc = concat!("position of [`", stringify!($mesh), "::merge_to_vertex_indices`].")]
+            pub fn merge_from_vertex_indices(&self) -> Vec<$index> {
+                self.copy_buffer(self.mesh_merge_count(), $merge_from)
+            }
+
+            /// Returns a copy of the vertices the vertices of
+            #[doc = concat!("[`", stringify!($mesh), "::merge_from_vertex_indices`] are merged into.")]
+            pub fn merge_to_vertex_indices(&self) -> Vec<$index> {
+                self.copy_buffer(self.mesh_merge_count(), $merge_to)
+            }
+
+            /// Returns a copy of the vertex index offsets at which the triangle runs start,
+            /// followed by the total vertex index count.
+            pub fn run_indices(&self) -> Vec<$index> {
+                self.copy_buffer(self.run_index_count(), $run_indices)
+            }
+
+            /// Returns a copy of the original IDs of the meshes each triangle run originates
+            /// from.
+            pub fn run_original_ids(&self) -> Vec<u32> {
+                self.copy_buffer(self.run_original_id_count(), $run_original_ids)
+            }
+
+            /// Returns a copy of the column-major 4x3 transforms of the triangle runs, twelve
+            /// values per run.
+            pub fn run_transforms(&self) -> Vec<$float> {
+                self.copy_buffer(self.run_transform_count(), $run_transforms)
+            }
+
+            /// Returns a copy of the face IDs, one per triangle.
+            pub fn face_ids(&self) -> Vec<$index> {
+                self.copy_buffer(self.face_id_count(), $face_ids)
+            }
+
+            /// Returns a copy of the halfedge tangents, four values per halfedge.
+            pub fn halfedge_tangents(&self) -> Vec<$float> {
+                self.copy_buffer(self.tangent_count(), $halfedge_tangents)
+            }
+
+            /// Returns copies of all buffers of the mesh.
+            pub fn buffers(&self) -> $buffers {
+                $buffers {
+                    properties_per_vertex: self.properties_per_vertex_count() as usize,
+                    vertex_properties: self.vertex_properties(),
+                    vertex_indices: self.vertex_indices(),
+                    merge_from_vertex_indices: self.merge_from_vertex_indices(),
+                    merge_to_vertex_indices: self.merge_to_vertex_indices(),
+                    run_indices: self.run_indices(),
+                    run_original_ids: self.run_original_ids(),
+                    run_transforms: self.run_transforms(),
+                    face_ids: self.face_ids(),
+                    halfedge_tangents: self.halfedge_tangents(),
+                }
+            }
+
+            /// Creates a mesh from the vertex properties, triangles and halfedge tangents of the
+            /// buffers, and recomputes the merge vectors of vertices sharing a position.
+            ///
+            /// Manifold offers no way to hand merge vectors, runs or face IDs over to a mesh, so
+            /// those buffers are not restored.
+            pub fn from_buffers(buffers: &$buffers) -> Result<$mesh, Error> {
+                let mesh = if buffers.halfedge_tangents.is_empty() {
+                    $mesh::new(
+                        &buffers.vertex_properties,
+                        buffers.properties_per_vertex,
+                        &buffers.vertex_indices,
+                    )
+                } else {
+                    $mesh::new_with_tangents(
+                        &buffers.vertex_properties,
+                        buffers.properties_per_vertex,
+                        &buffers.vertex_indices,
+                        &buffers.halfedge_tangents,
+                    )
+                }?;
+                Ok(mesh.merge().unwrap_or(mesh))
+            }
+
+            fn copy_buffer<T>(
+                &self,
+                length: usize,
+                copy: unsafe extern "C" fn(*mut c_void, *mut $raw) -> *mut T,
+            ) -> Vec<T> {
+                let mut buffer = Vec::<T>::with_capacity(length);
+                unsafe {
+                    copy(buffer.as_mut_ptr() as *mut c_void, self.0);
+                    buffer.set_len(length);
+                }
+                buffer
+            }
+        }
+
+        impl Clone for $mesh {
+            fn clone(&self) -> Self {
+                let mesh_gl_ptr = unsafe { $copy($alloc() as *mut c_void, self.0) };
+                $mesh(mesh_gl_ptr)
+            }
+        }
+
+        impl Drop for $mesh {
+            fn drop(&mut self) {
+                unsafe { $delete(self.0) }
+            }
+        }
 
-    pub fn merge(&self) -> Option<MeshGL> {
-        let duplicate_ptr = unsafe { manifold_alloc_meshgl() };
-        let returned_ptr = unsafe { manifold_meshgl_merge(duplicate_ptr as *mut c_void, self.0) };
+        #[doc = concat!("Serializes all buffers of the mesh, see [`", stringify!($mesh), "::buffers`].")]
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $mesh {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serde::Serialize::serialize(&self.buffers(), serializer)
+            }
+        }
 
-        // If the pointer to the duplicate_ptr was returned it means the operation was successful
-        if duplicate_ptr == returned_ptr {
-            return Some(MeshGL(duplicate_ptr));
+        #[doc = concat!("Restores a mesh like [`", stringify!($mesh), "::from_buffers`], which")]
+        /// recomputes the merge vectors but drops the runs and face IDs. Deserialize the
+        #[doc = concat!("[`", stringify!($buffers), "`] to read those.")]
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $mesh {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let buffers = <$buffers as serde::Deserialize>::deserialize(deserializer)?;
+                $mesh::from_buffers(&buffers).map_err(|error| {
+                    <D::Error as serde::de::Error>::custom(format!(
+                        "invalid mesh buffers: {:?}",
+                        error
+                    ))
+                })
+            }
+        }
+    };
+}
+
+pub(crate) use impl_mesh_gl;
+
+/// A mesh in the flat GL-style buffers of single precision, as used for rendering.
+///
+/// # Examples
+/// ```
+/// use manifold3d::{Manifold, MeshGL};
+///
+/// let vertex_properties = [
+///     0.0, 0.0, 0.0, //
+///     1.0, 0.0, 0.0, //
+///     0.0, 1.0, 0.0, //
+///     0.0, 0.0, 1.0,
+/// ];
+/// let vertex_indices = [0, 2, 1, 0, 1, 3, 1, 2, 3, 2, 0, 3];
+/// let mesh_gl = MeshGL::new(&vertex_properties, 3, &vertex_indices).unwrap();
+///
+/// let tetrahedron = Manifold::from_mesh_gl(&mesh_gl).unwrap();
+/// assert_eq!(tetrahedron.triangle_count(), 4);
+/// ```
+pub struct MeshGL(*mut ManifoldMeshGL);
+
+impl_mesh_gl!(
+    MeshGL(ManifoldMeshGL), f32, u32,
+    buffers: MeshGLBuffers,
+    alloc: manifold_alloc_meshgl,
+    delete: manifold_delete_meshgl,
+    copy: manifold_meshgl_copy,
+    new: manifold_meshgl,
+    new_with_tangents: manifold_meshgl_w_tangents,
+    merge: manifold_meshgl_merge,
+    vertex_properties: manifold_meshgl_vert_properties / manifold_meshgl_vert_properties_length,
+    vertex_indices: manifold_meshgl_tri_verts / manifold_meshgl_tri_length,
+    merge_vectors: manifold_meshgl_merge_from_vert, manifold_meshgl_merge_to_vert
+        / manifold_meshgl_merge_length,
+    run_indices: manifold_meshgl_run_index / manifold_meshgl_run_index_length,
+    run_original_ids: manifold_meshgl_run_original_id / manifold_meshgl_run_original_id_length,
+    run_transforms: manifold_meshgl_run_transform / manifold_meshgl_run_transform_length,
+    face_ids: manifold_meshgl_face_id / manifold_meshgl_face_id_length,
+    halfedge_tangents: manifold_meshgl_halfedge_tangent / manifold_meshgl_tangent_length,
+);
+
+/// Selects the edges returned by [`MeshGL::crease_halfedges`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CreaseOptions {
+    /// The dihedral angle from which an edge is a crease, i.e. the angle between the normals
+    /// of its two triangles: 0° for flat edges, 90° for the edges of a cube.
+    pub min_angle: NormalizedAngle,
+    /// Whether only edges between triangles of different face IDs can be creases, so the
+    /// edges inside a face, e.g. across a coarsely tessellated fillet, stay smooth.
+    pub face_boundaries_only: bool,
+}
+
+impl Default for CreaseOptions {
+    /// Creases from 30° on, regardless of face IDs.
+    fn default() -> Self {
+        Self {
+            min_angle: NormalizedAngle::from_degrees(30.0),
+            face_boundaries_only: false,
         }
-        None
     }
+}
 
+impl MeshGL {
     /// Constructs a smooth version of the input [`MeshGL`] mesh by creating tangents.
     ///
     /// The actual triangle resolution remains unchanged; use [`Manifold::refine_via_edge_splits`]
@@ -55,6 +400,7 @@ impl MeshGL {
     ///   of the sharpened edge. Vertices with only one sharpened edge are completely smooth, enabling
     ///   sharpened edges to smoothly disappear at their ends. To sharpen a single vertex, sharpen all
     ///   incident edges, which facilitates forming cones.
+    ///   [`MeshGL::crease_halfedges`] computes them from the dihedral angles of the mesh.
     ///
     /// # Returns
     ///
@@ -112,6 +458,81 @@ impl MeshGL {
         check_error(Manifold::from_ptr(manifold_ptr))
     }
 
+    /// Finds the creases of the mesh by their dihedral angle and returns their halfedges with
+    /// a smoothness, ready for [`MeshGL::smooth`].
+    ///
+    /// Halfedges are paired across vertices sharing a position through the merge vectors.
+    /// Both halfedges of every crease are returned, ordered by their index, which is
+    /// 3 * triangle index + 0, 1, 2 like in [`MeshGL::smooth`]. Edges without a partner
+    /// halfedge and edges of degenerate triangles are skipped. If the mesh has no face IDs,
+    /// every triangle is a face of its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `options`: The angle threshold and whether to consider face ID boundaries only.
+    /// * `smoothness`: Maps the dihedral angle of a crease in degrees to its smoothness,
+    ///   which is clamped to [0, 1]. Zero gives a sharp crease.
+    ///
+    /// # Examples
+    /// ```
+    /// use manifold3d::types::PositiveF64;
+    /// use manifold3d::{CreaseOptions, Manifold};
+    ///
+    /// let cube = Manifold::new_cuboid(
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     PositiveF64::new(1.0).unwrap(),
+    ///     true,
+    /// );
+    /// let mesh_gl = cube.as_mesh();
+    ///
+    /// let creases = mesh_gl.crease_halfedges(&CreaseOptions::default(), |_| 0.0);
+    /// // Both halfedges of the 12 edges of the cube
+    /// assert_eq!(creases.len(), 24);
+    /// let smoothed = mesh_gl.smooth(Some(creases)).unwrap();
+    /// ```
+    pub fn crease_halfedges(
+        &self,
+        options: &CreaseOptions,
+        smoothness: impl Fn(f64) -> Smoothness,
+    ) -> Vec<(HalfEdgeIndex, Smoothness)> {
+        let properties_per_vertex = self.properties_per_vertex_count() as usize;
+        let positions = self
+            .vertex_properties()
+            .chunks_exact(properties_per_vertex)
+            .map(|vertex| [0, 1, 2].map(|axis| f64::from(vertex[axis])))
+            .collect::<Vec<_>>();
+        let merged = merged_vertices(
+            self.vertex_count() as usize,
+            self.merge_from_vertex_indices(),
+            self.merge_to_vertex_indices(),
+        );
+        let triangles = self
+            .vertex_indices()
+            .chunks_exact(3)
+            .map(|triangle| [0, 1, 2].map(|corner| merged[triangle[corner] as usize]))
+            .collect::<Vec<_>>();
+        let face_ids = self.face_ids();
+
+        let min_angle = options.min_angle.get();
+        let mut creases = Vec::new();
+        for edge in dihedral_edges(&positions, &triangles) {
+            if options.face_boundaries_only
+                && !face_ids.is_empty()
+                && face_ids[edge.halfedge / 3] == face_ids[edge.pair / 3]
+            {
+                continue;
+            }
+            if edge.angle >= min_angle {
+                let smoothness = smoothness(edge.angle).clamp(0.0, 1.0);
+                creases.push((edge.halfedge, smoothness));
+                creases.push((edge.pair, smoothness));
+            }
+        }
+        creases.sort_unstable_by_key(|&(halfedge, _)| halfedge);
+        creases
+    }
+
     pub fn properties_per_vertex_count(&self) -> i32 {
         unsafe { manifold_meshgl_num_prop(self.0) }
     }
@@ -123,61 +544,27 @@ impl MeshGL {
     pub fn triangle_count(&self) -> i32 {
         unsafe { manifold_meshgl_num_tri(self.0) }
     }
-
-    /// Returns the length of the flat GL-style interleaved list of all vertex properties.
-    pub fn vertex_property_count(&self) -> usize {
-        unsafe { manifold_meshgl_vert_properties_length(self.0) }
-    }
-
-    pub fn vertex_index_count(&self) -> usize {
-        unsafe { manifold_meshgl_tri_length(self.0) }
-    }
-
-    pub fn mesh_merge_count(&self) -> usize {
-        unsafe { manifold_meshgl_merge_length(self.0) }
-    }
-
-    pub fn run_index_count(&self) -> usize {
-        unsafe { manifold_meshgl_run_index_length(self.0) }
-    }
-
-    pub fn run_original_id_count(&self) -> usize {
-        unsafe { manifold_meshgl_run_original_id_length(self.0) }
-    }
-
-    pub fn run_transform_count(&self) -> usize {
-        unsafe { manifold_meshgl_run_transform_length(self.0) }
-    }
-
-    pub fn face_id_count(&self) -> usize {
-        unsafe { manifold_meshgl_face_id_length(self.0) }
-    }
-
-    pub fn tangent_count(&self) -> usize {
-        unsafe { manifold_meshgl_tangent_length(self.0) }
-    }
-
-    /// Returns a copy of the original data
-    pub fn vertex_properties(&self) -> Vec<f32> {
-        let element_count = self.vertex_property_count();
-        let layout = Layout::array::<f32>(element_count).unwrap();
-        let array_start_ptr = unsafe { alloc(layout) } as *mut f32;
-        unsafe { manifold_meshgl_vert_properties(array_start_ptr as *mut c_void, self.0) };
-
-        unsafe { Vec::from_raw_parts(array_start_ptr, element_count, element_count) }
-    }
 }
 
-impl Clone for MeshGL {
-    fn clone(&self) -> Self {
-        let mesh_gl_ptr =
-            unsafe { manifold_meshgl_copy(manifold_alloc_meshgl() as *mut c_void, self.0) };
-        MeshGL(mesh_gl_ptr)
+/// Checks the buffers handed to a mesh constructor, as Manifold does not validate them.
+pub(crate) fn validate_buffers<I: Copy + Into<u64>>(
+    vertex_property_count: usize,
+    properties_per_vertex: usize,
+    vertex_indices: &[I],
+) -> Result<(), Error> {
+    if properties_per_vertex < 3 {
+        return Err(Error::MissingPositionProperties);
     }
-}
-
-impl Drop for MeshGL {
-    fn drop(&mut self) {
-        unsafe { manifold_delete_meshgl(self.0) }
+    if !vertex_property_count.is_multiple_of(properties_per_vertex) {
+        return Err(Error::PropertiesWrongLength);
+    }
+    let vertex_count = (vertex_property_count / properties_per_vertex) as u64;
+    if !vertex_indices.len().is_multiple_of(3)
+        || vertex_indices
+            .iter()
+            .any(|index| (*index).into() >= vertex_count)
+    {
+        return Err(Error::VertexIndexOutOfBounds);
     }
+    Ok(())
 }
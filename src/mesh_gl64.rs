@@ -0,0 +1,54 @@
+use crate::mesh_gl::impl_mesh_gl;
+use crate::Error;
+use manifold3d_sys::{
+    manifold_alloc_meshgl64, manifold_delete_meshgl64, manifold_meshgl64, manifold_meshgl64_copy,
+    manifold_meshgl64_face_id, manifold_meshgl64_face_id_length,
+    manifold_meshgl64_halfedge_tangent, manifold_meshgl64_merge, manifold_meshgl64_merge_from_vert,
+    manifold_meshgl64_merge_length, manifold_meshgl64_merge_to_vert, manifold_meshgl64_num_prop,
+    manifold_meshgl64_num_tri, manifold_meshgl64_num_vert, manifold_meshgl64_run_index,
+    manifold_meshgl64_run_index_length, manifold_meshgl64_run_original_id,
+    manifold_meshgl64_run_original_id_length, manifold_meshgl64_run_transform,
+    manifold_meshgl64_run_transform_length, manifold_meshgl64_tangent_length,
+    manifold_meshgl64_tri_length, manifold_meshgl64_tri_verts, manifold_meshgl64_vert_properties,
+    manifold_meshgl64_vert_properties_length, manifold_meshgl64_w_tangents, ManifoldMeshGL64,
+};
+use std::os::raw::c_void;
+
+/// The double precision variant of [`crate::MeshGL`], which represents the vertex properties
+/// without losing precision of the manifold's internal representation.
+pub struct MeshGL64(*mut ManifoldMeshGL64);
+
+impl_mesh_gl!(
+    MeshGL64(ManifoldMeshGL64), f64, u64,
+    buffers: MeshGL64Buffers,
+    alloc: manifold_alloc_meshgl64,
+    delete: manifold_delete_meshgl64,
+    copy: manifold_meshgl64_copy,
+    new: manifold_meshgl64,
+    new_with_tangents: manifold_meshgl64_w_tangents,
+    merge: manifold_meshgl64_merge,
+    vertex_properties: manifold_meshgl64_vert_properties / manifold_meshgl64_vert_properties_length,
+    vertex_indices: manifold_meshgl64_tri_verts / manifold_meshgl64_tri_length,
+    merge_vectors: manifold_meshgl64_merge_from_vert, manifold_meshgl64_merge_to_vert
+        / manifold_meshgl64_merge_length,
+    run_indices: manifold_meshgl64_run_index / manifold_meshgl64_run_index_length,
+    run_original_ids: manifold_meshgl64_run_original_id
+        / manifold_meshgl64_run_original_id_length,
+    run_transforms: manifold_meshgl64_run_transform / manifold_meshgl64_run_transform_length,
+    face_ids: manifold_meshgl64_face_id / manifold_meshgl64_face_id_length,
+    halfedge_tangents: manifold_meshgl64_halfedge_tangent / manifold_meshgl64_tangent_length,
+);
+
+impl MeshGL64 {
+    pub fn properties_per_vertex_count(&self) -> usize {
+        unsafe { manifold_meshgl64_num_prop(self.0) }
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        unsafe { manifold_meshgl64_num_vert(self.0) }
+    }
+
+    pub fn triangle_count(&self) -> usize {
+        unsafe { manifold_meshgl64_num_tri(self.0) }
+    }
+}
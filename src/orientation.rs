@@ -0,0 +1,207 @@
+//! Choosing the orientation in which to build a part layer by layer, e.g. with a 3D printer.
+
+use crate::manifold::geometry::{cross, dot, length, scale, sub, TriangleMesh, Vector};
+use crate::manifold::overhang_region;
+use crate::types::{Matrix4x3, NormalizedAngle, Vec3};
+use crate::Manifold;
+
+/// The weights and search settings of [`optimize`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrientationCriteria {
+    /// The largest angle from the build direction which can be built without support, see
+    /// [`Manifold::overhang_analysis`].
+    pub max_overhang_angle: NormalizedAngle,
+    /// The weight of the overhanging area.
+    pub overhang_area_weight: f64,
+    /// The weight of the height of the part above the build plate.
+    pub build_height_weight: f64,
+    /// The weight of the area resting on the build plate, which lowers the score.
+    pub contact_area_weight: f64,
+    /// The weight of the volume of the suggested support.
+    pub support_volume_weight: f64,
+    /// The maximum number of faces of the convex hull the part is placed on, largest first.
+    pub hull_face_count: usize,
+    /// The number of build directions sampled evenly over the sphere in addition.
+    pub sampled_direction_count: usize,
+}
+
+impl Default for OrientationCriteria {
+    fn default() -> Self {
+        Self {
+            max_overhang_angle: NormalizedAngle::from_degrees(45.0),
+            overhang_area_weight: 1.0,
+            build_height_weight: 1.0,
+            contact_area_weight: 1.0,
+            support_volume_weight: 1.0,
+            hull_face_count: 32,
+            sampled_direction_count: 32,
+        }
+    }
+}
+
+/// A candidate orientation, as returned by [`optimize`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Orientation {
+    /// The rigid transformation which puts the part onto the build plate: the build
+    /// direction becomes the Z axis and the lowest point lies at Z = 0.
+    pub transform: Matrix4x3,
+    /// The unit build direction in the frame of the original part.
+    pub build_direction: Vec3,
+    /// The weighted score, lower is better.
+    pub score: f64,
+    pub overhang_area: f64,
+    pub build_height: f64,
+    pub contact_area: f64,
+    pub support_volume: f64,
+}
+
+/// Searches the orientations of a part for the one which is best to build.
+///
+/// The candidates place the part onto each of the largest faces of its
+/// [`Manifold::convex_hull`], on which it rests stably, and additionally point the build
+/// direction along directions sampled evenly over the sphere. Each candidate is scored by
+/// the weighted sum of its overhang area, build height and support volume, minus the
+/// weighted area resting on the build plate.
+///
+/// # Returns
+///
+/// The candidates ordered by their score, best first. An empty part has no candidates.
+///
+/// # Examples
+/// ```
+/// use manifold3d::orientation::{self, OrientationCriteria};
+/// use manifold3d::types::{PositiveF64, Vec3};
+/// use manifold3d::Manifold;
+///
+/// let cube = Manifold::new_cuboid(
+///     PositiveF64::new(1.0).unwrap(),
+///     PositiveF64::new(1.0).unwrap(),
+///     PositiveF64::new(1.0).unwrap(),
+///     false,
+/// );
+/// // A tall post with a wide base
+/// let part = cube
+///     .scale(Vec3::new(4.0, 4.0, 1.0))
+///     .union(&cube.scale(Vec3::new(1.0, 1.0, 6.0)));
+///
+/// let ranked = orientation::optimize(&part, &OrientationCriteria::default());
+/// // Standing on its base needs no support
+/// assert_eq!(ranked[0].build_direction, Vec3::new(0.0, 0.0, 1.0));
+/// assert_eq!(ranked[0].support_volume, 0.0);
+///
+/// let placed = part.transform(ranked[0].transform);
+/// assert_eq!(placed.bounding_box().min_point().z, 0.0);
+/// ```
+pub fn optimize(manifold: &Manifold, criteria: &OrientationCriteria) -> Vec<Orientation> {
+    if manifold.is_empty() {
+        return Vec::new();
+    }
+    let min_downward = criteria
+        .max_overhang_angle
+        .get()
+        .clamp(0.0, 90.0)
+        .to_radians()
+        .sin();
+
+    let mut directions: Vec<Vector> = Vec::new();
+    let candidates = hull_face_directions(manifold)
+        .into_iter()
+        .take(criteria.hull_face_count)
+        .chain(sphere_directions(criteria.sampled_direction_count));
+    for direction in candidates {
+        if directions
+            .iter()
+            .all(|&existing| dot(existing, direction) < 1.0 - 1e-9)
+        {
+            directions.push(direction);
+        }
+    }
+
+    let mut orientations = directions
+        .into_iter()
+        .map(|direction| evaluate(manifold, direction, min_downward, criteria))
+        .collect::<Vec<_>>();
+    orientations.sort_by(|a, b| a.score.total_cmp(&b.score));
+    orientations
+}
+
+fn evaluate(
+    manifold: &Manifold,
+    direction: Vector,
+    min_downward: f64,
+    criteria: &OrientationCriteria,
+) -> Orientation {
+    let transform = manifold.plate_frame(direction);
+    let on_plate = manifold.transform(transform);
+    let build_height = on_plate.bounding_box().max_point().z;
+    let (overhang_area, region) = overhang_region(&on_plate, min_downward);
+    let support_volume = region.map_or(0.0, |region| region.difference(&on_plate).volume());
+    let contact_area = contact_area(&on_plate);
+
+    Orientation {
+        transform,
+        build_direction: Vec3::new(direction[0], direction[1], direction[2]),
+        score: criteria.overhang_area_weight * overhang_area
+            + criteria.build_height_weight * build_height
+            + criteria.support_volume_weight * support_volume
+            - criteria.contact_area_weight * contact_area,
+        overhang_area,
+        build_height,
+        contact_area,
+        support_volume,
+    }
+}
+
+/// Returns the area of the triangles lying flat on the X-Y plane.
+fn contact_area(on_plate: &Manifold) -> f64 {
+    let mesh = TriangleMesh::of_manifold(on_plate);
+    let tolerance = on_plate
+        .epsilon()
+        .max(1e-9 * on_plate.bounding_box().scale());
+    (0..mesh.triangles.len())
+        .map(|triangle| mesh.corners(triangle))
+        .filter(|corners| corners.iter().all(|corner| corner[2].abs() <= tolerance))
+        .map(|[a, b, c]| length(cross(sub(b, a), sub(c, a))) / 2.0)
+        .sum()
+}
+
+/// Returns the build directions which place the manifold on the faces of its convex hull,
+/// ordered by the area of the faces, largest first.
+fn hull_face_directions(manifold: &Manifold) -> Vec<Vector> {
+    let mesh = TriangleMesh::of_manifold(&manifold.convex_hull());
+    // The outward normals of the coplanar faces with their total area
+    let mut faces: Vec<(Vector, f64)> = Vec::new();
+    for triangle in 0..mesh.triangles.len() {
+        let [a, b, c] = mesh.corners(triangle);
+        let normal = cross(sub(b, a), sub(c, a));
+        let double_area = length(normal);
+        if double_area == 0.0 {
+            continue;
+        }
+        let normal = scale(normal, 1.0 / double_area);
+        match faces
+            .iter_mut()
+            .find(|(existing, _)| dot(*existing, normal) > 1.0 - 1e-9)
+        {
+            Some((_, area)) => *area += double_area / 2.0,
+            None => faces.push((normal, double_area / 2.0)),
+        }
+    }
+    faces.sort_by(|a, b| b.1.total_cmp(&a.1));
+    // Resting on a face points its outward normal down
+    faces
+        .into_iter()
+        .map(|(normal, _)| scale(normal, -1.0))
+        .collect()
+}
+
+/// Returns directions spread evenly over the unit sphere on a Fibonacci spiral.
+fn sphere_directions(count: usize) -> impl Iterator<Item = Vector> {
+    let golden_angle = std::f64::consts::PI * (3.0 - 5.0f64.sqrt());
+    (0..count).map(move |index| {
+        let z = 1.0 - 2.0 * (index as f64 + 0.5) / count as f64;
+        let radius = (1.0 - z * z).sqrt();
+        let angle = golden_angle * index as f64;
+        [radius * angle.cos(), radius * angle.sin(), z]
+    })
+}
@@ -0,0 +1,308 @@
+//! Arranging several parts side by side on a rectangular build plate.
+
+use crate::manifold_vec::ManifoldVec;
+use crate::types::{Matrix4x3, NonNegativeF64, PositiveF64, Vec3};
+use crate::Manifold;
+
+/// The plate and spacing used by [`pack`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PackingOptions {
+    /// The extent of the plate along the X axis, starting at the origin.
+    pub plate_width: PositiveF64,
+    /// The extent of the plate along the Y axis, starting at the origin.
+    pub plate_depth: PositiveF64,
+    /// The minimum distance between the footprints of two parts.
+    pub spacing: NonNegativeF64,
+    /// The number of rotations about the Z axis tried per part, evenly spread over a full
+    /// turn. One keeps the parts unrotated, four tries steps of 90°.
+    pub rotation_count: usize,
+}
+
+impl Default for PackingOptions {
+    /// A plate of 200 by 200 with a spacing of 2, trying steps of 90°.
+    fn default() -> Self {
+        Self {
+            plate_width: PositiveF64::new(200.0).unwrap(),
+            plate_depth: PositiveF64::new(200.0).unwrap(),
+            spacing: NonNegativeF64::new(2.0).unwrap(),
+            rotation_count: 4,
+        }
+    }
+}
+
+/// The position of a part on the plate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Placement {
+    /// The index of the part in the packed slice.
+    pub index: usize,
+    /// The rotation about the Z axis, applied first.
+    pub rotation_degrees: f64,
+    /// The translation applied after the rotation, which also puts the part onto the plate
+    /// at Z = 0.
+    pub translation: Vec3,
+    /// The rotation followed by the translation, for [`Manifold::transform`].
+    pub transform: Matrix4x3,
+}
+
+/// The result of [`pack`].
+pub struct Packing {
+    /// The placements of all parts which fit onto the plate, ordered by their indices.
+    pub placements: Vec<Placement>,
+    /// The indices of the parts which did not fit onto the plate.
+    pub unplaced: Vec<usize>,
+    /// All placed parts composed into one manifold.
+    pub composed: Manifold,
+}
+
+/// Places the parts on the plate without their footprints overlapping.
+///
+/// The footprints are the bounding rectangles of [`Manifold::project`], rotated about the Z
+/// axis. Parts are placed largest first, each at the position and rotation which keeps the
+/// packed rows lowest along the Y axis, preferring small X coordinates. Empty parts are not
+/// placed.
+///
+/// # Examples
+/// ```
+/// use manifold3d::packing::{self, PackingOptions};
+/// use manifold3d::types::PositiveF64;
+/// use manifold3d::Manifold;
+///
+/// let part = Manifold::new_cuboid(
+///     PositiveF64::new(40.0).unwrap(),
+///     PositiveF64::new(30.0).unwrap(),
+///     PositiveF64::new(10.0).unwrap(),
+///     true,
+/// );
+/// let parts = vec![part; 12];
+///
+/// let packing = packing::pack(&parts, &PackingOptions::default());
+/// assert_eq!(packing.placements.len(), 12);
+/// assert!(packing.unplaced.is_empty());
+/// assert!((packing.composed.volume() - 12.0 * 12000.0).abs() < 1e-6);
+/// ```
+pub fn pack(manifolds: &[Manifold], options: &PackingOptions) -> Packing {
+    let rotation_count = options.rotation_count.max(1);
+    let footprints = manifolds
+        .iter()
+        .map(|manifold| Footprint::of(manifold, rotation_count))
+        .collect::<Vec<_>>();
+
+    let mut order = (0..manifolds.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| footprints[b].area().total_cmp(&footprints[a].area()));
+
+    let spacing = options.spacing.get();
+    let mut skyline = Skyline::new(
+        options.plate_width.get() + spacing,
+        options.plate_depth.get() + spacing,
+    );
+    let mut placements = Vec::new();
+    let mut unplaced = Vec::new();
+    for index in order {
+        let footprint = &footprints[index];
+        if footprint.bounds.is_empty() {
+            unplaced.push(index);
+            continue;
+        }
+        let best = footprint
+            .bounds
+            .iter()
+            .enumerate()
+            .filter_map(|(rotation, bounds)| {
+                let width = bounds.max_x - bounds.min_x + spacing;
+                let depth = bounds.max_y - bounds.min_y + spacing;
+                skyline
+                    .find(width, depth)
+                    .map(|(x, y)| (rotation, x, y, width, depth))
+            })
+            .min_by(|a, b| {
+                (a.2 + a.4)
+                    .total_cmp(&(b.2 + b.4))
+                    .then(a.1.total_cmp(&b.1))
+            });
+        let Some((rotation, x, y, width, depth)) = best else {
+            unplaced.push(index);
+            continue;
+        };
+        skyline.insert(x, y + depth, width);
+
+        let bounds = &footprint.bounds[rotation];
+        let rotation_degrees = rotation_degrees(rotation, rotation_count);
+        let (sin, cos) = rotation_degrees.to_radians().sin_cos();
+        let translation = Vec3::new(x - bounds.min_x, y - bounds.min_y, -footprint.min_z);
+        placements.push(Placement {
+            index,
+            rotation_degrees,
+            translation,
+            transform: Matrix4x3::new([
+                Vec3::new(cos, sin, 0.0),
+                Vec3::new(-sin, cos, 0.0),
+                Vec3::new(0.0, 0.0, 1.0),
+                translation,
+            ]),
+        });
+    }
+    placements.sort_by_key(|placement| placement.index);
+    unplaced.sort_unstable();
+
+    let placed = placements
+        .iter()
+        .map(|placement| manifolds[placement.index].transform(placement.transform))
+        .collect::<Vec<_>>();
+    Packing {
+        placements,
+        unplaced,
+        composed: ManifoldVec::from_manifolds(&placed).compose(),
+    }
+}
+
+fn rotation_degrees(rotation: usize, rotation_count: usize) -> f64 {
+    360.0 * rotation as f64 / rotation_count as f64
+}
+
+/// The bounds of a footprint rotated about the Z axis.
+struct Bounds {
+    min_x: f64,
+    max_x: f64,
+    min_y: f64,
+    max_y: f64,
+}
+
+struct Footprint {
+    /// The bounds for every rotation, empty for empty manifolds.
+    bounds: Vec<Bounds>,
+    min_z: f64,
+}
+
+impl Footprint {
+    fn of(manifold: &Manifold, rotation_count: usize) -> Footprint {
+        if manifold.is_empty() {
+            return Footprint {
+                bounds: Vec::new(),
+                min_z: 0.0,
+            };
+        }
+        let points = manifold
+            .project()
+            .as_vec()
+            .iter()
+            .flat_map(|polygon| polygon.as_vec())
+            .collect::<Vec<_>>();
+        let bounds = (0..rotation_count)
+            .map(|rotation| {
+                let (sin, cos) = rotation_degrees(rotation, rotation_count)
+                    .to_radians()
+                    .sin_cos();
+                let mut bounds = Bounds {
+                    min_x: f64::INFINITY,
+                    max_x: f64::NEG_INFINITY,
+                    min_y: f64::INFINITY,
+                    max_y: f64::NEG_INFINITY,
+                };
+                for point in &points {
+                    let x = cos * point.x - sin * point.y;
+                    let y = sin * point.x + cos * point.y;
+                    bounds.min_x = bounds.min_x.min(x);
+                    bounds.max_x = bounds.max_x.max(x);
+                    bounds.min_y = bounds.min_y.min(y);
+                    bounds.max_y = bounds.max_y.max(y);
+                }
+                bounds
+            })
+            .collect();
+        Footprint {
+            bounds,
+            min_z: manifold.bounding_box().min_point().z,
+        }
+    }
+
+    /// Returns the area of the unrotated bounds.
+    fn area(&self) -> f64 {
+        self.bounds.first().map_or(0.0, |bounds| {
+            (bounds.max_x - bounds.min_x) * (bounds.max_y - bounds.min_y)
+        })
+    }
+}
+
+/// The upper outline of the rectangles packed so far, as segments ordered along the X axis
+/// which cover the whole width.
+struct Skyline {
+    segments: Vec<Segment>,
+    depth: f64,
+}
+
+struct Segment {
+    x: f64,
+    width: f64,
+    y: f64,
+}
+
+impl Skyline {
+    fn new(width: f64, depth: f64) -> Skyline {
+        Skyline {
+            segments: vec![Segment {
+                x: 0.0,
+                width,
+                y: 0.0,
+            }],
+            depth,
+        }
+    }
+
+    /// Finds the lowest position at which a rectangle fits, starting at a segment.
+    fn find(&self, width: f64, depth: f64) -> Option<(f64, f64)> {
+        let end = self.segments.last().map_or(0.0, |last| last.x + last.width);
+        let mut best: Option<(f64, f64)> = None;
+        for (start, segment) in self.segments.iter().enumerate() {
+            let x = segment.x;
+            if x + width > end {
+                break;
+            }
+            let y = self.segments[start..]
+                .iter()
+                .take_while(|other| other.x < x + width)
+                .map(|other| other.y)
+                .fold(0.0, f64::max);
+            if y + depth <= self.depth && best.is_none_or(|(_, best_y)| y < best_y) {
+                best = Some((x, y));
+            }
+        }
+        best
+    }
+
+    /// Raises the outline to `y` from `x` over `width`.
+    fn insert(&mut self, x: f64, y: f64, width: f64) {
+        let end = x + width;
+        let mut segments = Vec::with_capacity(self.segments.len() + 2);
+        for segment in self.segments.drain(..) {
+            let segment_end = segment.x + segment.width;
+            if segment.x < x {
+                segments.push(Segment {
+                    width: segment_end.min(x) - segment.x,
+                    ..segment
+                });
+            }
+            if segment.x < end
+                && segment_end > x
+                && segments.last().is_none_or(|last| last.x + last.width <= x)
+            {
+                segments.push(Segment { x, width, y });
+            }
+            if segment_end > end {
+                let start = segment.x.max(end);
+                segments.push(Segment {
+                    x: start,
+                    width: segment_end - start,
+                    y: segment.y,
+                });
+            }
+        }
+        // Merge neighbors of equal height
+        self.segments = Vec::with_capacity(segments.len());
+        for segment in segments {
+            match self.segments.last_mut() {
+                Some(last) if last.y == segment.y => last.width += segment.width,
+                _ => self.segments.push(segment),
+            }
+        }
+    }
+}
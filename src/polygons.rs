@@ -17,18 +17,17 @@ pub struct Polygons(*mut ManifoldPolygons);
 impl Polygons {
     pub fn from_simple_polygons(simple_polygons: Vec<SimplePolygon>) -> Polygons {
         let simple_polygons_length = simple_polygons.len();
-        let mut simple_polygons = simple_polygons
-            .into_iter()
-            .map(|p| p.ptr())
-            .collect::<Vec<_>>();
+        // The simple polygons are copied by manifold, but have to stay alive until then
+        let mut simple_polygon_ptrs = simple_polygons.iter().map(|p| p.ptr()).collect::<Vec<_>>();
 
         let polygons_ptr = unsafe {
             manifold_polygons(
                 manifold_alloc_polygons() as *mut c_void,
-                simple_polygons.as_mut_ptr(),
+                simple_polygon_ptrs.as_mut_ptr(),
                 simple_polygons_length,
             )
         };
+        drop(simple_polygons);
         Polygons::from_ptr(polygons_ptr)
     }
 
@@ -125,6 +124,23 @@ impl Polygons {
     }
 }
 
+/// Serialized as the sequence of its simple polygons, each being a sequence of points.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Polygons {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_vec())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Polygons {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let simple_polygons =
+            <Vec<SimplePolygon> as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(Polygons::from_simple_polygons(simple_polygons))
+    }
+}
+
 impl Drop for Polygons {
     fn drop(&mut self) {
         unsafe { manifold_delete_polygons(self.0) }
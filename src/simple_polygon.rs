@@ -40,11 +40,37 @@ impl SimplePolygon {
         Some(Point2::from(vec))
     }
 
+    pub fn as_vec(&self) -> Vec<Point2> {
+        let mut points = Vec::<Point2>::with_capacity(self.point_count());
+
+        for i in 0..self.point_count() {
+            points.push(self.get_point(i).unwrap());
+        }
+
+        points
+    }
+
     pub(crate) fn ptr(&self) -> *mut ManifoldSimplePolygon {
         self.0
     }
 }
 
+/// Serialized as the sequence of its points.
+#[cfg(feature = "serde")]
+impl serde::Serialize for SimplePolygon {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_vec())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SimplePolygon {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let points = <Vec<Point2> as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(SimplePolygon::new_from_points(points))
+    }
+}
+
 impl Drop for SimplePolygon {
     fn drop(&mut self) {
         unsafe { manifold_delete_simple_polygon(self.0) }
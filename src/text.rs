@@ -0,0 +1,274 @@
+//! Laying out text in TrueType and OpenType fonts as [`Polygons`], e.g. for labels and
+//! engravings.
+
+use crate::io::contour::{curve_segments, into_polygons};
+use crate::types::{Point2, PositiveF64};
+use crate::Polygons;
+use std::path::Path;
+use thiserror::Error;
+use ttf_parser::{Face, FaceParsingError, GlyphId, OutlineBuilder};
+
+/// Errors that can occur while loading a font.
+#[derive(Error, Debug)]
+pub enum FontError {
+    #[error("failed to read the font file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid font data: {0}")]
+    InvalidFont(#[from] FaceParsingError),
+}
+
+/// The horizontal position of the lines relative to the origin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HorizontalAlignment {
+    /// Lines start at the origin.
+    #[default]
+    Left,
+    /// Lines are centered on the origin.
+    Center,
+    /// Lines end at the origin.
+    Right,
+}
+
+/// The vertical position of the text block relative to the origin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerticalAlignment {
+    /// The first line rests on the origin.
+    #[default]
+    Baseline,
+    /// The ascender of the first line touches the origin.
+    Top,
+    /// The block is centered on the origin, between the ascender of the first and the
+    /// descender of the last line.
+    Middle,
+    /// The descender of the last line touches the origin.
+    Bottom,
+}
+
+/// Options controlling the layout of [`Font::text`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextOptions {
+    /// The height of the em square of the font in model units, which roughly spans from
+    /// the descender to the ascender.
+    pub size: PositiveF64,
+    pub horizontal_alignment: HorizontalAlignment,
+    pub vertical_alignment: VerticalAlignment,
+    /// The distance between the baselines of consecutive lines as a multiple of the line
+    /// height of the font.
+    pub line_spacing: PositiveF64,
+}
+
+impl Default for TextOptions {
+    /// A size of 10, left aligned on the baseline with single line spacing.
+    fn default() -> Self {
+        Self {
+            size: PositiveF64::new(10.0).unwrap(),
+            horizontal_alignment: HorizontalAlignment::default(),
+            vertical_alignment: VerticalAlignment::default(),
+            line_spacing: PositiveF64::new(1.0).unwrap(),
+        }
+    }
+}
+
+/// A TrueType or OpenType font.
+pub struct Font {
+    data: Vec<u8>,
+    index: u32,
+}
+
+impl Font {
+    /// Loads the first font of the font data, which may also be a font collection.
+    pub fn from_bytes(data: impl Into<Vec<u8>>) -> Result<Font, FontError> {
+        let data = data.into();
+        Face::parse(&data, 0)?;
+        Ok(Font { data, index: 0 })
+    }
+
+    /// Loads the first font of a font file, which may also be a font collection.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Font, FontError> {
+        Font::from_bytes(std::fs::read(path)?)
+    }
+
+    /// Lays out the text and returns the outlines of its glyphs in the X-Y plane, with the
+    /// Y axis pointing up.
+    ///
+    /// Every `\n` starts a new line below the previous one. Glyphs are placed by their
+    /// advance widths and the pair kerning of the font's `kern` table, characters missing
+    /// from the font are drawn as its missing glyph.
+    ///
+    /// Curves are flattened with the global quality settings like in
+    /// [`crate::io::svg::polygons_from_svg_path`], so lowering the minimum circular angle or
+    /// edge length produces finer outlines. Contours are oriented by their nesting depth,
+    /// counter-clockwise for outlines and clockwise for holes, so the result can be passed
+    /// to [`Polygons::extrude`] directly.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use manifold3d::text::{Font, HorizontalAlignment, TextOptions};
+    /// use manifold3d::types::{PositiveF64, PositiveI32};
+    ///
+    /// let font = Font::from_path("DejaVuSans.ttf").unwrap();
+    /// let options = TextOptions {
+    ///     size: PositiveF64::new(5.0).unwrap(),
+    ///     horizontal_alignment: HorizontalAlignment::Center,
+    ///     ..TextOptions::default()
+    /// };
+    ///
+    /// let label = font
+    ///     .text("Part 42", &options)
+    ///     .extrude(
+    ///         PositiveF64::new(1.0).unwrap(),
+    ///         PositiveI32::new(1).unwrap(),
+    ///         0.0,
+    ///         None::<manifold3d::types::Vec2>,
+    ///     )
+    ///     .unwrap();
+    /// ```
+    pub fn text(&self, text: &str, options: &TextOptions) -> Polygons {
+        // The data was validated when loading the font
+        let face = Face::parse(&self.data, self.index).unwrap();
+        let scale = options.size.get() / f64::from(face.units_per_em());
+        let ascender = f64::from(face.ascender()) * scale;
+        let descender = f64::from(face.descender()) * scale;
+        let line_height = ascender - descender + f64::from(face.line_gap()) * scale;
+        let line_advance = line_height * options.line_spacing.get();
+
+        let lines = text.split('\n').collect::<Vec<_>>();
+        let last_baseline = -line_advance * (lines.len() - 1) as f64;
+        let offset_y = match options.vertical_alignment {
+            VerticalAlignment::Baseline => 0.0,
+            VerticalAlignment::Top => -ascender,
+            VerticalAlignment::Middle => -(ascender + last_baseline + descender) / 2.0,
+            VerticalAlignment::Bottom => -(last_baseline + descender),
+        };
+
+        let mut contours = Vec::new();
+        for (line_index, line) in lines.iter().enumerate() {
+            let glyphs = line
+                .chars()
+                .map(|character| face.glyph_index(character).unwrap_or(GlyphId(0)))
+                .collect::<Vec<_>>();
+            let mut positions = Vec::with_capacity(glyphs.len());
+            let mut x = 0.0;
+            for (index, &glyph) in glyphs.iter().enumerate() {
+                if index > 0 {
+                    x += f64::from(kerning(&face, glyphs[index - 1], glyph)) * scale;
+                }
+                positions.push(x);
+                x += f64::from(face.glyph_hor_advance(glyph).unwrap_or(0)) * scale;
+            }
+            let offset_x = match options.horizontal_alignment {
+                HorizontalAlignment::Left => 0.0,
+                HorizontalAlignment::Center => -x / 2.0,
+                HorizontalAlignment::Right => -x,
+            };
+
+            let baseline = offset_y - line_advance * line_index as f64;
+            for (&glyph, position) in glyphs.iter().zip(positions) {
+                let mut builder = ContourBuilder {
+                    scale,
+                    origin: Point2::new(offset_x + position, baseline),
+                    contours: Vec::new(),
+                    current: Vec::new(),
+                    cursor: Point2::new(0.0, 0.0),
+                };
+                face.outline_glyph(glyph, &mut builder);
+                builder.flush();
+                contours.append(&mut builder.contours);
+            }
+        }
+        into_polygons(contours)
+    }
+}
+
+/// Returns the horizontal kerning between two glyphs in font units.
+fn kerning(face: &Face, left: GlyphId, right: GlyphId) -> i16 {
+    face.tables()
+        .kern
+        .and_then(|kern| {
+            kern.subtables
+                .into_iter()
+                .filter(|subtable| subtable.horizontal && !subtable.variable)
+                .find_map(|subtable| subtable.glyphs_kerning(left, right))
+        })
+        .unwrap_or(0)
+}
+
+/// Collects the flattened contours of a glyph, scaled from font units and moved to the
+/// origin of the glyph.
+struct ContourBuilder {
+    scale: f64,
+    origin: Point2,
+    contours: Vec<Vec<Point2>>,
+    current: Vec<Point2>,
+    /// The current point in font units.
+    cursor: Point2,
+}
+
+impl ContourBuilder {
+    fn push(&mut self, point: Point2) {
+        self.current.push(Point2::new(
+            self.origin.x + point.x * self.scale,
+            self.origin.y + point.y * self.scale,
+        ));
+        self.cursor = point;
+    }
+
+    fn flush(&mut self) {
+        let mut contour = std::mem::take(&mut self.current);
+        // The closing point duplicates the start of the contour
+        if contour.len() > 1 && contour.first() == contour.last() {
+            contour.pop();
+        }
+        if !contour.is_empty() {
+            self.contours.push(contour);
+        }
+    }
+}
+
+impl OutlineBuilder for ContourBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.flush();
+        self.push(Point2::new(f64::from(x), f64::from(y)));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.push(Point2::new(f64::from(x), f64::from(y)));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let from = self.cursor;
+        let c = Point2::new(f64::from(x1), f64::from(y1));
+        let to = Point2::new(f64::from(x), f64::from(y));
+        let segments = curve_segments(&[from, c, to], self.scale);
+        for i in 1..=segments {
+            let t = i as f64 / segments as f64;
+            let s = 1.0 - t;
+            let (w0, w1, w2) = (s * s, 2.0 * s * t, t * t);
+            self.push(Point2::new(
+                w0 * from.x + w1 * c.x + w2 * to.x,
+                w0 * from.y + w1 * c.y + w2 * to.y,
+            ));
+        }
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let from = self.cursor;
+        let c1 = Point2::new(f64::from(x1), f64::from(y1));
+        let c2 = Point2::new(f64::from(x2), f64::from(y2));
+        let to = Point2::new(f64::from(x), f64::from(y));
+        let segments = curve_segments(&[from, c1, c2, to], self.scale);
+        for i in 1..=segments {
+            let t = i as f64 / segments as f64;
+            let s = 1.0 - t;
+            let (w0, w1, w2, w3) = (s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t);
+            self.push(Point2::new(
+                w0 * from.x + w1 * c1.x + w2 * c2.x + w3 * to.x,
+                w0 * from.y + w1 * c1.y + w2 * c2.y + w3 * to.y,
+            ));
+        }
+    }
+
+    fn close(&mut self) {
+        self.flush();
+    }
+}
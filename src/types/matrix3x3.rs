@@ -0,0 +1,46 @@
+use crate::types::Vec3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Matrix3x3 {
+    pub rows: [Vec3; 3],
+}
+
+impl Matrix3x3 {
+    pub fn new(rows: [Vec3; 3]) -> Self {
+        Self { rows }
+    }
+}
+
+#[cfg(feature = "nalgebra_interop")]
+impl From<nalgebra::Matrix3<f64>> for Matrix3x3 {
+    fn from(matrix: nalgebra::Matrix3<f64>) -> Self {
+        Matrix3x3 {
+            rows: [
+                Vec3 {
+                    x: matrix.m11,
+                    y: matrix.m12,
+                    z: matrix.m13,
+                },
+                Vec3 {
+                    x: matrix.m21,
+                    y: matrix.m22,
+                    z: matrix.m23,
+                },
+                Vec3 {
+                    x: matrix.m31,
+                    y: matrix.m32,
+                    z: matrix.m33,
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra_interop")]
+impl From<Matrix3x3> for nalgebra::Matrix3<f64> {
+    fn from(matrix: Matrix3x3) -> Self {
+        let [a, b, c] = matrix.rows;
+        nalgebra::Matrix3::new(a.x, a.y, a.z, b.x, b.y, b.z, c.x, c.y, c.z)
+    }
+}
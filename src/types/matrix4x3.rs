@@ -1,6 +1,7 @@
 use crate::types::Vec3;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Matrix4x3 {
     pub rows: [Vec3; 4],
 }
@@ -1,3 +1,4 @@
+mod matrix3x3;
 mod matrix4x3;
 mod non_negative_num;
 mod normalized_angle;
@@ -7,6 +8,7 @@ mod positive_num;
 mod vec2;
 mod vec3;
 
+pub use matrix3x3::*;
 pub use matrix4x3::*;
 pub use non_negative_num::*;
 pub use normalized_angle::*;
@@ -29,6 +29,26 @@ impl<T: num_traits::Num + PartialOrd + Copy> NonNegativeNum<T> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for NonNegativeNum<T>
+where
+    T: num_traits::Num + PartialOrd + Copy + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for NonNegativeNum<T>
+where
+    T: num_traits::Num + PartialOrd + Copy + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        NonNegativeNum::new(T::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
 macro_rules! impl_positive_num_from {
     ($ttype:ident, $underlying_primitive:ident, ($($from_type:ty),+)) => {
         $(
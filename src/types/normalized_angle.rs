@@ -30,6 +30,24 @@ impl NormalizedAngle {
     }
 }
 
+/// Serialized as degrees.
+#[cfg(feature = "serde")]
+impl serde::Serialize for NormalizedAngle {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+/// Deserialized from degrees, which are normalized like in [`NormalizedAngle::from_degrees`].
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NormalizedAngle {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(NormalizedAngle::from_degrees(f64::deserialize(
+            deserializer,
+        )?))
+    }
+}
+
 impl<T> From<PositiveNum<T>> for NormalizedAngle
 where
     T: num_traits::Num + PartialOrd + Copy,
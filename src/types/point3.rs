@@ -3,6 +3,7 @@ use std::ops::{Add, Sub};
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point3 {
     pub x: f64,
     pub y: f64,
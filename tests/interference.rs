@@ -0,0 +1,111 @@
+use manifold3d::interference;
+use manifold3d::types::{NonNegativeF64, Point3, PositiveF64, Vec3};
+use manifold3d::Manifold;
+
+#[test]
+fn test_check_reports_overlap_volume_and_bounds() {
+    let parts = [
+        Manifold::new_cuboid(
+            PositiveF64::new(2.0).unwrap(),
+            PositiveF64::new(2.0).unwrap(),
+            PositiveF64::new(2.0).unwrap(),
+            false,
+        ),
+        Manifold::new_cuboid(
+            PositiveF64::new(2.0).unwrap(),
+            PositiveF64::new(2.0).unwrap(),
+            PositiveF64::new(2.0).unwrap(),
+            false,
+        )
+        .translate(Vec3::new(1.0, 1.0, 1.0)),
+        Manifold::new_cuboid(
+            PositiveF64::new(1.0).unwrap(),
+            PositiveF64::new(1.0).unwrap(),
+            PositiveF64::new(1.0).unwrap(),
+            false,
+        )
+        .translate(Vec3::new(20.0, 0.0, 0.0)),
+    ];
+
+    let report = interference::check(&parts, NonNegativeF64::new(0.0).unwrap());
+    assert_eq!(report.overlaps.len(), 1);
+    let overlap = &report.overlaps[0];
+    assert_eq!((overlap.first, overlap.second), (0, 1));
+    assert!((overlap.volume - 1.0).abs() < 1e-9);
+    assert_eq!(overlap.bounding_box.min_point(), Point3::new(1.0, 1.0, 1.0));
+    assert_eq!(overlap.bounding_box.max_point(), Point3::new(2.0, 2.0, 2.0));
+    assert!(report.near_misses.is_empty());
+    assert!(!report.is_clear());
+}
+
+#[test]
+fn test_check_flags_near_misses_within_clearance() {
+    let parts = [
+        Manifold::new_cuboid(
+            PositiveF64::new(1.0).unwrap(),
+            PositiveF64::new(1.0).unwrap(),
+            PositiveF64::new(1.0).unwrap(),
+            false,
+        ),
+        // Touching the first one
+        Manifold::new_cuboid(
+            PositiveF64::new(1.0).unwrap(),
+            PositiveF64::new(1.0).unwrap(),
+            PositiveF64::new(1.0).unwrap(),
+            false,
+        )
+        .translate(Vec3::new(1.0, 0.0, 0.0)),
+        // 0.3 away from the second one
+        Manifold::new_cuboid(
+            PositiveF64::new(1.0).unwrap(),
+            PositiveF64::new(1.0).unwrap(),
+            PositiveF64::new(1.0).unwrap(),
+            false,
+        )
+        .translate(Vec3::new(2.3, 0.0, 0.0)),
+        // Diagonally 0.3 away in X, but far away in Y
+        Manifold::new_cuboid(
+            PositiveF64::new(1.0).unwrap(),
+            PositiveF64::new(1.0).unwrap(),
+            PositiveF64::new(1.0).unwrap(),
+            false,
+        )
+        .translate(Vec3::new(3.6, 5.0, 0.0)),
+    ];
+
+    let report = interference::check(&parts, NonNegativeF64::new(0.5).unwrap());
+    assert!(report.overlaps.is_empty());
+    let near_misses = report
+        .near_misses
+        .iter()
+        .map(|near_miss| (near_miss.first, near_miss.second))
+        .collect::<Vec<_>>();
+    assert_eq!(near_misses, vec![(0, 1), (1, 2)]);
+    assert!(report.near_misses[0].gap.abs() < 1e-9);
+    assert!((report.near_misses[1].gap - 0.3).abs() < 1e-9);
+
+    let report = interference::check(&parts, NonNegativeF64::new(0.1).unwrap());
+    assert_eq!(report.near_misses.len(), 1);
+}
+
+#[test]
+fn test_check_of_many_separated_parts_is_clear() {
+    let parts = (0..200)
+        .map(|index| {
+            Manifold::new_cuboid(
+                PositiveF64::new(1.0).unwrap(),
+                PositiveF64::new(1.0).unwrap(),
+                PositiveF64::new(1.0).unwrap(),
+                false,
+            )
+            .translate(Vec3::new(
+                (index % 20) as f64 * 2.0,
+                (index / 20) as f64 * 2.0,
+                0.0,
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    let report = interference::check(&parts, NonNegativeF64::new(0.5).unwrap());
+    assert!(report.is_clear());
+}
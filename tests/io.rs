@@ -0,0 +1,236 @@
+use manifold3d::io::dxf::{
+    layers_to_dxf, polygons_from_dxf, polygons_to_dxf, DxfImportError, DxfLayer,
+};
+use manifold3d::io::svg::{
+    layers_to_svg, polygons_from_svg, polygons_from_svg_path, polygons_to_svg, SvgImportError,
+    SvgLayer, SvgOptions, SvgUnit,
+};
+use manifold3d::types::{Point2, PositiveF64};
+use manifold3d::{FillRule, Manifold, Polygons, SimplePolygon};
+
+fn square(min: f64, max: f64) -> SimplePolygon {
+    SimplePolygon::new_from_points(vec![
+        Point2::new(min, min),
+        Point2::new(max, min),
+        Point2::new(max, max),
+        Point2::new(min, max),
+    ])
+}
+
+#[test]
+fn test_svg_export_of_polygons() {
+    let polygons = Polygons::from_simple_polygons(vec![square(0.0, 2.0), square(0.5, 1.5)]);
+    let options = SvgOptions {
+        fill_rule: FillRule::EvenOdd,
+        scale: 10.0,
+        unit: SvgUnit::Pixel,
+        ..SvgOptions::default()
+    };
+
+    let svg = polygons_to_svg(&polygons, &options);
+
+    assert!(svg.contains("width=\"20px\" height=\"20px\" viewBox=\"0 -20 20 20\""));
+    assert!(svg.contains("fill-rule=\"evenodd\""));
+    assert!(svg.contains("d=\"M0,0 L20,0 L20,-20 L0,-20 Z M5,-5 L15,-5 L15,-15 L5,-15 Z\""));
+}
+
+#[test]
+fn test_svg_export_of_slice_layers() {
+    let manifold = Manifold::new_cuboid(
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        false,
+    );
+    let slices =
+        [0.25, 0.5, 0.75].map(|height| manifold.slice_by_height(PositiveF64::new(height).unwrap()));
+    let layers = slices
+        .iter()
+        .enumerate()
+        .map(|(index, slice)| SvgLayer::new(format!("slice {}", index), slice))
+        .collect::<Vec<_>>();
+
+    let svg = layers_to_svg(&layers, &SvgOptions::default());
+
+    assert_eq!(svg.matches("inkscape:groupmode=\"layer\"").count(), 3);
+    assert_eq!(svg.matches("<path").count(), 3);
+    assert!(svg.contains("viewBox=\"0 -1 1 1\""));
+}
+
+fn signed_area(polygon: &SimplePolygon) -> f64 {
+    let points = polygon.as_vec();
+    let mut area = 0.0;
+    for (index, point) in points.iter().enumerate() {
+        let next = points[(index + 1) % points.len()];
+        area += point.x * next.y - next.x * point.y;
+    }
+    area / 2.0
+}
+
+#[test]
+fn test_svg_import_round_trips_export() {
+    let polygons = Polygons::from_simple_polygons(vec![square(0.0, 2.0), square(0.5, 1.5)]);
+    let svg = polygons_to_svg(&polygons, &SvgOptions::default());
+
+    let imported = polygons_from_svg(&svg).unwrap().as_vec();
+
+    assert_eq!(imported.len(), 2);
+    assert_eq!(imported[0].as_vec(), square(0.0, 2.0).as_vec());
+    assert_eq!(signed_area(&imported[0]), 4.0);
+    assert_eq!(signed_area(&imported[1]), -1.0);
+}
+
+#[test]
+fn test_svg_import_of_curves_and_arcs() {
+    // A circle of radius 10 made of two arcs and one made of four cubic Béziers
+    let arcs = polygons_from_svg_path("M10,0 A10,10 0 1,0 -10,0 a10 10 0 1 0 20 0z").unwrap();
+    let cubics = polygons_from_svg_path(
+        "M10,0 C10,5.523 5.523,10 0,10 S-10,5.523 -10,0 S-5.523,-10 0,-10 S10,-5.523 10,0 Z",
+    )
+    .unwrap();
+
+    for polygons in [arcs, cubics] {
+        let polygon = polygons.get(0).unwrap();
+        assert!(polygon.point_count() > 16);
+        assert!((signed_area(&polygon) - 100.0 * std::f64::consts::PI).abs() < 5.0);
+        for point in polygon.as_vec() {
+            assert!((point.x.hypot(point.y) - 10.0).abs() < 0.01);
+        }
+    }
+}
+
+#[test]
+fn test_svg_import_applies_group_transforms() {
+    let document = r#"<?xml version="1.0"?>
+        <svg xmlns="http://www.w3.org/2000/svg">
+            <!-- <path d="M0,0 L1,0 L1,1 Z" /> -->
+            <defs><rect width="100" height="100" /></defs>
+            <g transform="translate(10 20)">
+                <g transform="scale(2)">
+                    <rect x="1" y="1" width="2" height="3" />
+                </g>
+            </g>
+        </svg>"#;
+
+    let polygons = polygons_from_svg(document).unwrap();
+
+    assert_eq!(polygons.count(), 1);
+    let points = polygons.get(0).unwrap().as_vec();
+    assert!(points.contains(&Point2::new(12.0, -22.0)));
+    assert!(points.contains(&Point2::new(16.0, -28.0)));
+    assert_eq!(signed_area(&polygons.get(0).unwrap()), 24.0);
+}
+
+#[test]
+fn test_svg_import_rejects_invalid_path_data() {
+    assert_eq!(
+        polygons_from_svg_path("M0,0 L1").err(),
+        Some(SvgImportError::MissingParameters { command: 'L' })
+    );
+    assert_eq!(
+        polygons_from_svg_path("M0,0 X1,1").err(),
+        Some(SvgImportError::UnexpectedCharacter {
+            character: 'X',
+            offset: 5
+        })
+    );
+}
+
+fn dxf_entities(entities: &str) -> String {
+    format!("0\nSECTION\n2\nENTITIES\n{}0\nENDSEC\n0\nEOF\n", entities)
+}
+
+#[test]
+fn test_dxf_export_round_trips_import() {
+    let outer = Polygons::from_simple_polygons(vec![square(0.0, 2.0), square(0.5, 1.5)]);
+    let inner = Polygons::from_simple_polygons(vec![square(0.75, 1.25)]);
+
+    let dxf = layers_to_dxf(&[
+        DxfLayer::new("outer", &outer),
+        DxfLayer::new("inner: cut", &inner),
+    ]);
+
+    assert_eq!(dxf.matches("POLYLINE").count(), 3);
+    assert_eq!(dxf.matches("VERTEX").count(), 12);
+    assert!(dxf.contains("\n  2\ninner_ cut\n"));
+
+    let imported = polygons_from_dxf(&dxf).unwrap().as_vec();
+    assert_eq!(imported.len(), 3);
+    assert_eq!(imported[0].as_vec(), square(0.0, 2.0).as_vec());
+    assert_eq!(signed_area(&imported[1]), -1.0);
+    assert_eq!(signed_area(&imported[2]), 0.25);
+
+    let single = polygons_from_dxf(&polygons_to_dxf(&inner)).unwrap();
+    assert_eq!(single.get(0).unwrap().as_vec(), square(0.75, 1.25).as_vec());
+}
+
+#[test]
+fn test_dxf_import_of_bulged_lightweight_polyline() {
+    // Two half circles of radius 5 around the origin
+    let dxf = dxf_entities(
+        "0\nLWPOLYLINE\n8\n0\n90\n2\n70\n1\n10\n5\n20\n0\n42\n1\n10\n-5\n20\n0\n42\n1\n",
+    );
+
+    let polygon = polygons_from_dxf(&dxf).unwrap().get(0).unwrap();
+
+    assert!(polygon.point_count() > 8);
+    assert!((signed_area(&polygon) - 25.0 * std::f64::consts::PI).abs() < 2.0);
+    for point in polygon.as_vec() {
+        assert!((point.x.hypot(point.y) - 5.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_dxf_import_chains_lines_and_arcs() {
+    // A slot made of two lines and two half circle arcs, drawn in arbitrary directions
+    let dxf = dxf_entities(concat!(
+        "0\nLINE\n8\ncut\n10\n0\n20\n0\n11\n100\n21\n0\n",
+        "0\nLINE\n8\ncut\n10\n100\n20\n20\n11\n0\n21\n20\n",
+        "0\nARC\n8\ncut\n10\n100\n20\n10\n40\n10\n50\n270\n51\n90\n",
+        "0\nARC\n8\ncut\n10\n0\n20\n10\n40\n10\n50\n90\n51\n270\n",
+        "0\nCIRCLE\n8\nholes\n10\n50\n20\n10\n40\n5\n",
+    ));
+
+    let polygons = polygons_from_dxf(&dxf).unwrap().as_vec();
+
+    assert_eq!(polygons.len(), 2);
+    let areas = polygons.iter().map(signed_area).collect::<Vec<_>>();
+    assert!(areas
+        .iter()
+        .any(|area| (area - (2000.0 + 100.0 * std::f64::consts::PI)).abs() < 5.0));
+    assert!(areas
+        .iter()
+        .any(|area| (area + 25.0 * std::f64::consts::PI).abs() < 1.0));
+}
+
+#[test]
+fn test_dxf_import_skips_open_pieces() {
+    // A square, a construction line starting at one of its corners, a separate center
+    // mark and a line of zero length
+    let dxf = dxf_entities(concat!(
+        "0\nLINE\n8\ncut\n10\n0\n20\n0\n11\n10\n21\n0\n",
+        "0\nLINE\n8\ncut\n10\n10\n20\n0\n11\n10\n21\n10\n",
+        "0\nLINE\n8\ncut\n10\n10\n20\n10\n11\n20\n21\n20\n",
+        "0\nLINE\n8\ncut\n10\n10\n20\n10\n11\n0\n21\n10\n",
+        "0\nLINE\n8\ncut\n10\n0\n20\n10\n11\n0\n21\n0\n",
+        "0\nLINE\n8\ncenter\n10\n4\n20\n5\n11\n6\n21\n5\n",
+        "0\nLINE\n8\ncut\n10\n3\n20\n3\n11\n3\n21\n3\n",
+    ));
+
+    let polygons = polygons_from_dxf(&dxf).unwrap().as_vec();
+
+    assert_eq!(polygons.len(), 1);
+    assert_eq!(signed_area(&polygons[0]), 100.0);
+    assert_eq!(
+        polygons_from_dxf(&dxf_entities(
+            "0\nLINE\n8\ncut\n10\n0\n20\n0\n11\n1\n21\n0\n"
+        ))
+        .unwrap()
+        .count(),
+        0
+    );
+    assert_eq!(
+        polygons_from_dxf("0\nSECTION\n2\nHEADER\n0\nENDSEC\n").err(),
+        Some(DxfImportError::MissingEntities)
+    );
+}
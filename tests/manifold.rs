@@ -1,7 +1,7 @@
 use manifold3d::macros::manifold;
-use manifold3d::manifold::{BooleanOperation, ReplaceVertexProperties};
-use manifold3d::types::{Point3, PositiveF64};
-use manifold3d::{types, Manifold};
+use manifold3d::manifold::{BooleanOperation, ReplaceVertexProperties, SnapshotError};
+use manifold3d::types::{NonNegativeF64, Point3, PositiveF64, PositiveI32};
+use manifold3d::{types, BoundingBox, Manifold};
 use std::pin::Pin;
 
 #[test]
@@ -190,3 +190,1166 @@ fn test_replace_vertex_properties() {
     let new_manifold = manifold.replace_vertex_properties(Pin::new(&replacer));
     println!("{}", new_manifold.properties_per_vertex_count());
 }
+
+#[test]
+fn test_level_set_sphere() {
+    #[manifold::sdf]
+    pub struct SphereSdf {
+        radius: f64,
+    }
+
+    impl manifold3d::manifold::SignedDistance for SphereSdf {
+        fn signed_distance(&self, point: types::Point3) -> f64 {
+            self.radius - (point.x * point.x + point.y * point.y + point.z * point.z).sqrt()
+        }
+    }
+
+    let sphere = SphereSdf { radius: 2.0 };
+    let bounds = BoundingBox::new(Point3::new(-3.0, -3.0, -3.0), Point3::new(3.0, 3.0, 3.0));
+    let manifold = Manifold::from_level_set(
+        Pin::new(&sphere),
+        &bounds,
+        PositiveF64::new(0.1).unwrap(),
+        0.0,
+        None,
+    );
+
+    let expected_volume = 4.0 / 3.0 * std::f64::consts::PI * 8.0;
+    assert!((manifold.volume() - expected_volume).abs() / expected_volume < 0.01);
+    assert_eq!(manifold.genus(), 0);
+
+    // A positive level insets the surface
+    let inset = Manifold::from_level_set(
+        Pin::new(&sphere),
+        &bounds,
+        PositiveF64::new(0.1).unwrap(),
+        1.0,
+        None,
+    );
+    let expected_volume = 4.0 / 3.0 * std::f64::consts::PI;
+    assert!((inset.volume() - expected_volume).abs() / expected_volume < 0.02);
+}
+
+#[test]
+fn test_level_set_from_closure_is_clipped_by_bounds() {
+    let bounds = BoundingBox::new(Point3::new(0.0, 0.0, 0.0), Point3::new(4.0, 4.0, 4.0));
+    // Positive everywhere, so the whole bounding box is filled
+    let manifold = Manifold::from_level_set_fn(
+        |_point: Point3| 1.0,
+        &bounds,
+        PositiveF64::new(0.5).unwrap(),
+        0.0,
+        None,
+    );
+    let bounding_box = manifold.bounding_box();
+    assert_eq!(bounding_box.min_point(), Point3::new(0.0, 0.0, 0.0));
+    assert_eq!(bounding_box.max_point(), Point3::new(4.0, 4.0, 4.0));
+    assert!((manifold.volume() - 64.0).abs() < 1e-6);
+
+    let gyroid = Manifold::from_level_set_fn(
+        |point: Point3| {
+            let (x, y, z) = (point.x * 2.0, point.y * 2.0, point.z * 2.0);
+            x.sin() * y.cos() + y.sin() * z.cos() + z.sin() * x.cos()
+        },
+        &bounds,
+        PositiveF64::new(0.2).unwrap(),
+        0.0,
+        Some(PositiveF64::new(0.01).unwrap()),
+    );
+    // The gyroid splits space into two congruent halves
+    assert!((gyroid.volume() - 32.0).abs() < 3.0);
+    assert!(bounds.contains_bounding_box(&gyroid.bounding_box()));
+}
+
+#[test]
+fn test_snapshot_round_trip() {
+    let cuboid = Manifold::new_cuboid(
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(3.0).unwrap(),
+        true,
+    );
+    let sphere = Manifold::new_sphere(PositiveF64::new(1.2).unwrap(), None::<PositiveI32>);
+    let product = cuboid.difference(&sphere);
+
+    for manifold in [cuboid, product] {
+        let loaded = Manifold::from_bytes(&manifold.to_bytes()).unwrap();
+
+        assert_eq!(loaded.volume(), manifold.volume());
+        assert_eq!(loaded.genus(), manifold.genus());
+        assert_eq!(loaded.triangle_count(), manifold.triangle_count());
+        // Original IDs are remapped, as loaded manifolds are new originals
+        assert!(loaded.original_id().is_some());
+        assert_ne!(loaded.original_id(), manifold.original_id());
+
+        // The snapshot keeps every buffer, including the runs and face IDs
+        let snapshot = Manifold::decode_snapshot(&manifold.to_bytes()).unwrap();
+        assert_eq!(snapshot.original_id, manifold.original_id());
+        assert_eq!(snapshot.mesh, manifold.as_mesh64().buffers());
+        assert!(!snapshot.mesh.face_ids.is_empty());
+    }
+}
+
+#[test]
+fn test_snapshot_keeps_tangents() {
+    let smoothed = Manifold::new_tetrahedron().as_mesh().smooth(None).unwrap();
+    let tangent_count = smoothed.as_mesh().tangent_count();
+    assert!(tangent_count > 0);
+
+    let loaded = Manifold::from_bytes(&smoothed.to_bytes()).unwrap();
+
+    assert_eq!(loaded.as_mesh().tangent_count(), tangent_count);
+}
+
+#[test]
+fn test_snapshot_rejects_corrupted_data() {
+    let mut bytes = Manifold::new_tetrahedron().to_bytes();
+
+    assert_eq!(
+        Manifold::from_bytes(&bytes[..10]).err(),
+        Some(SnapshotError::Truncated)
+    );
+    assert_eq!(
+        Manifold::from_bytes(b"not a snapshot").err(),
+        Some(SnapshotError::InvalidMagic)
+    );
+
+    bytes[20] ^= 0xFF;
+    assert!(matches!(
+        Manifold::from_bytes(&bytes),
+        Err(SnapshotError::ChecksumMismatch { .. })
+    ));
+
+    bytes[4] = 99;
+    assert_eq!(
+        Manifold::from_bytes(&bytes).err(),
+        Some(SnapshotError::UnsupportedVersion(99))
+    );
+}
+
+#[test]
+fn test_simplify_restores_flat_faces() {
+    let cube = Manifold::new_cuboid(
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        true,
+    );
+    let refined = cube.refine_to_edge_length(NonNegativeF64::new(0.25).unwrap());
+    assert!(refined.triangle_count() > 12);
+
+    let simplification = refined.simplify(PositiveF64::new(1e-6).unwrap()).unwrap();
+    assert_eq!(
+        simplification.triangle_count_before,
+        refined.triangle_count()
+    );
+    assert_eq!(simplification.triangle_count_after, 12);
+    assert!((simplification.manifold.volume() - 8.0).abs() < 1e-9);
+    assert_eq!(simplification.manifold.genus(), 0);
+}
+
+#[test]
+fn test_simplify_stays_within_tolerance() {
+    let sphere = Manifold::new_sphere(
+        PositiveF64::new(5.0).unwrap(),
+        Some(PositiveI32::new(128).unwrap()),
+    );
+
+    let exact = sphere.simplify(PositiveF64::new(1e-9).unwrap()).unwrap();
+    assert_eq!(exact.removed_triangle_count(), 0);
+
+    let tolerance = 0.05;
+    let coarse = sphere
+        .simplify(PositiveF64::new(tolerance).unwrap())
+        .unwrap();
+    assert!(coarse.triangle_count_after < coarse.triangle_count_before / 2);
+    assert_eq!(coarse.manifold.genus(), 0);
+    let volume_change = (coarse.manifold.volume() - sphere.volume()).abs();
+    assert!(volume_change < tolerance * sphere.surface_area());
+}
+
+#[test]
+fn test_simplify_bounds_the_surface_deviation() {
+    // The refined sphere takes many collapse passes, whose errors must not add up
+    let sphere = Manifold::new_sphere(
+        PositiveF64::new(5.0).unwrap(),
+        Some(PositiveI32::new(16).unwrap()),
+    )
+    .as_mesh()
+    .smooth(None)
+    .unwrap()
+    .refine_via_edge_splits(
+        manifold3d::manifold::EdgeSplitCount::new(PositiveI32::new(6).unwrap()).unwrap(),
+    );
+
+    for tolerance in [0.02, 0.1, 0.5] {
+        let simplification = sphere
+            .simplify(PositiveF64::new(tolerance).unwrap())
+            .unwrap();
+        assert!(simplification.removed_triangle_count() > 0);
+        let distance = simplification
+            .manifold
+            .hausdorff_distance(&sphere, PositiveF64::new(50.0).unwrap());
+        assert!(distance <= tolerance * 1.01);
+    }
+}
+
+#[test]
+fn test_minkowski_sum_of_convex_manifolds() {
+    let cube = Manifold::new_cuboid(
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        true,
+    );
+    let small_cube = Manifold::new_cuboid(
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        true,
+    );
+
+    let sum = cube.minkowski_sum(&small_cube);
+    assert!((sum.volume() - 27.0).abs() < 1e-9);
+    assert_eq!(
+        sum.bounding_box().min_point(),
+        Point3::new(-1.5, -1.5, -1.5)
+    );
+}
+
+#[test]
+fn test_minkowski_sum_and_difference_of_non_convex_manifold() {
+    let cube = |size: f64| {
+        Manifold::new_cuboid(
+            PositiveF64::new(size).unwrap(),
+            PositiveF64::new(size).unwrap(),
+            PositiveF64::new(size).unwrap(),
+            true,
+        )
+    };
+    // A hollow box with walls of 2
+    let hollow = cube(8.0).difference(&cube(4.0));
+    let tool = cube(1.0);
+
+    // The outside grows by 0.5, the cavity shrinks by 0.5
+    let sum = hollow.minkowski_sum(&tool);
+    assert!((sum.volume() - (729.0 - 27.0)).abs() < 1e-6);
+    assert_eq!(sum.genus(), -1);
+
+    // The outside shrinks by 0.5, the cavity grows by 0.5
+    let difference = hollow.minkowski_difference(&tool);
+    assert!((difference.volume() - (343.0 - 125.0)).abs() < 1e-6);
+    assert_eq!(difference.genus(), -1);
+}
+
+#[test]
+fn test_point_queries_on_sphere() {
+    let sphere = Manifold::new_sphere(
+        PositiveF64::new(2.0).unwrap(),
+        Some(PositiveI32::new(64).unwrap()),
+    );
+
+    assert!(sphere.contains_point(Point3::new(0.0, 0.0, 0.0)));
+    assert!(sphere.contains_point(Point3::new(1.0, 1.0, 1.0)));
+    assert!(!sphere.contains_point(Point3::new(1.5, 1.5, 1.5)));
+
+    let distance = sphere.signed_distance(Point3::new(0.0, 0.0, 0.5));
+    assert!((distance - 1.5).abs() < 0.01);
+    let distance = sphere.signed_distance(Point3::new(5.0, 0.0, 0.0));
+    assert!((distance + 3.0).abs() < 0.01);
+
+    let closest = sphere.closest_point(Point3::new(0.0, 4.0, 0.0)).unwrap();
+    assert!((closest.distance - 2.0).abs() < 0.01);
+    let coordinates = closest.barycentric_coordinates;
+    assert!((coordinates.x + coordinates.y + coordinates.z - 1.0).abs() < 1e-12);
+
+    // The barycentric coordinates interpolate the triangle's corners
+    let mesh = sphere.as_mesh64();
+    let vertex_indices = mesh.vertex_indices();
+    let properties = mesh.vertex_properties();
+    let properties_per_vertex = mesh.properties_per_vertex_count();
+    let corner = |corner: usize, axis: usize| {
+        let vertex = vertex_indices[closest.triangle_index * 3 + corner] as usize;
+        properties[vertex * properties_per_vertex + axis]
+    };
+    let weights = [coordinates.x, coordinates.y, coordinates.z];
+    let interpolated = (0..3)
+        .map(|axis| (0..3).map(|c| corner(c, axis) * weights[c]).sum::<f64>())
+        .collect::<Vec<_>>();
+    assert!((interpolated[0] - closest.position.x).abs() < 1e-9);
+    assert!((interpolated[1] - closest.position.y).abs() < 1e-9);
+    assert!((interpolated[2] - closest.position.z).abs() < 1e-9);
+}
+
+#[test]
+fn test_point_queries_on_empty_manifold() {
+    let empty = Manifold::new_empty();
+    assert!(!empty.contains_point(Point3::new(0.0, 0.0, 0.0)));
+    assert_eq!(
+        empty.signed_distance(Point3::new(0.0, 0.0, 0.0)),
+        f64::NEG_INFINITY
+    );
+    assert!(empty.closest_point(Point3::new(0.0, 0.0, 0.0)).is_none());
+}
+
+#[test]
+fn test_ray_cast_reports_hit_details() {
+    let cube = Manifold::new_cuboid(
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        true,
+    );
+    let original_id = cube.original_id().unwrap() as u32;
+    let moved = cube.translate(types::Vec3::new(5.0, 0.0, 0.0));
+    let scene = cube.union(&moved);
+
+    let hits = scene.ray_cast(
+        Point3::new(-10.0, 0.3, 0.2),
+        types::Vec3::new(2.0, 0.0, 0.0),
+        f64::INFINITY,
+    );
+    let distances = hits.iter().map(|hit| hit.distance).collect::<Vec<_>>();
+    assert_eq!(distances, vec![9.0, 11.0, 14.0, 16.0]);
+    let entering = hits.iter().map(|hit| hit.is_entering).collect::<Vec<_>>();
+    assert_eq!(entering, vec![true, false, true, false]);
+    assert!(hits.iter().all(|hit| hit.original_id == original_id));
+    // Both cubes are instances of the same original, so their faces share IDs
+    assert_eq!(hits[0].face_id, hits[2].face_id);
+    assert_ne!(hits[0].face_id, hits[1].face_id);
+
+    let limited = scene.ray_cast(
+        Point3::new(-10.0, 0.3, 0.2),
+        types::Vec3::new(1.0, 0.0, 0.0),
+        12.0,
+    );
+    assert_eq!(limited.len(), 2);
+    assert!(scene
+        .ray_cast(
+            Point3::new(-10.0, 0.3, 0.2),
+            types::Vec3::new(0.0, 0.0, 0.0),
+            f64::INFINITY
+        )
+        .is_empty());
+}
+
+#[test]
+fn test_ray_cast_batch_matches_single_casts() {
+    let sphere = Manifold::new_sphere(
+        PositiveF64::new(1.0).unwrap(),
+        Some(PositiveI32::new(32).unwrap()),
+    );
+    let rays = (0..1000)
+        .map(|i| {
+            let angle = i as f64 * 0.01;
+            manifold3d::manifold::Ray::new(
+                Point3::new(0.0, 0.0, 0.0),
+                types::Vec3::new(angle.cos(), angle.sin(), 0.1),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let batch = sphere.query().ray_cast_batch(&rays, f64::INFINITY);
+    assert_eq!(batch, sphere.ray_cast_batch(&rays, f64::INFINITY));
+    assert_eq!(batch.len(), rays.len());
+    for (ray, hits) in rays.iter().zip(&batch) {
+        assert_eq!(
+            hits,
+            &sphere.ray_cast(ray.origin, ray.direction, f64::INFINITY)
+        );
+        assert!(!hits.is_empty());
+        assert!(!hits[0].is_entering);
+        assert!((hits[0].distance - 1.0).abs() < 0.01);
+    }
+}
+
+#[test]
+fn test_mass_properties_of_cuboid() {
+    let (x, y, z) = (1.0, 2.0, 3.0);
+    let cuboid = Manifold::new_cuboid(
+        PositiveF64::new(x).unwrap(),
+        PositiveF64::new(y).unwrap(),
+        PositiveF64::new(z).unwrap(),
+        false,
+    );
+    let density = 1.5;
+    let properties = cuboid.mass_properties(PositiveF64::new(density).unwrap());
+    let mass = density * x * y * z;
+    let near = |a: f64, b: f64| (a - b).abs() < 1e-9;
+
+    assert!(near(properties.mass, mass));
+    assert!(near(properties.volume, x * y * z));
+    assert!(near(properties.center_of_mass.x, 0.5));
+    assert!(near(properties.center_of_mass.y, 1.0));
+    assert!(near(properties.center_of_mass.z, 1.5));
+
+    let central = properties.inertia_tensor.rows;
+    assert!(near(central[0].x, mass * (y * y + z * z) / 12.0));
+    assert!(near(central[1].y, mass * (x * x + z * z) / 12.0));
+    assert!(near(central[2].z, mass * (x * x + y * y) / 12.0));
+    assert!(near(central[0].y, 0.0));
+
+    let about_origin = properties.inertia_tensor_about_origin.rows;
+    assert!(near(about_origin[0].x, mass * (y * y + z * z) / 3.0));
+    assert!(near(about_origin[0].y, -mass * x * y / 4.0));
+    assert!(near(about_origin[1].z, -mass * y * z / 4.0));
+
+    assert!(near(properties.principal_moments.x, central[2].z));
+    assert!(near(properties.principal_moments.z, central[0].x));
+}
+
+#[test]
+fn test_mass_properties_principal_axes_follow_rotation() {
+    let rod = Manifold::new_cuboid(
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(6.0).unwrap(),
+        true,
+    );
+    let (sin_x, cos_x) = 30.0f64.to_radians().sin_cos();
+    let (sin_z, cos_z) = 45.0f64.to_radians().sin_cos();
+    let rotated = rod
+        .transform(types::Matrix4x3::new([
+            types::Vec3::new(1.0, 0.0, 0.0),
+            types::Vec3::new(0.0, cos_x, sin_x),
+            types::Vec3::new(0.0, -sin_x, cos_x),
+            types::Vec3::new(0.0, 0.0, 0.0),
+        ]))
+        .transform(types::Matrix4x3::new([
+            types::Vec3::new(cos_z, sin_z, 0.0),
+            types::Vec3::new(-sin_z, cos_z, 0.0),
+            types::Vec3::new(0.0, 0.0, 1.0),
+            types::Vec3::new(3.0, -2.0, 1.0),
+        ]));
+    let properties = rotated.mass_properties(PositiveF64::new(1.0).unwrap());
+    let frame = properties.principal_axes.rows;
+
+    assert!((frame[3].x - 3.0).abs() < 1e-9);
+    assert!((frame[3].y + 2.0).abs() < 1e-9);
+    assert!((frame[3].z - 1.0).abs() < 1e-9);
+
+    // Transforming the rod into its principal frame aligns its long axis with X
+    let axis = [frame[0].x, frame[0].y, frame[0].z];
+    let extent = rotated
+        .as_mesh64()
+        .vertex_properties()
+        .chunks(3)
+        .fold(0.0f64, |extent, p| {
+            let along = (p[0] - 3.0) * axis[0] + (p[1] + 2.0) * axis[1] + (p[2] - 1.0) * axis[2];
+            extent.max(along.abs())
+        });
+    assert!((extent - 3.0).abs() < 1e-9);
+
+    let moments = properties.principal_moments;
+    assert!((moments.x - 6.0 * 2.0 / 12.0).abs() < 1e-9);
+    assert!((moments.y - 6.0 * 37.0 / 12.0).abs() < 1e-9);
+    assert!((moments.z - 6.0 * 37.0 / 12.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_closest_points_between_manifolds() {
+    let sphere = Manifold::new_sphere(
+        PositiveF64::new(1.0).unwrap(),
+        Some(PositiveI32::new(48).unwrap()),
+    );
+    let cube = Manifold::new_cuboid(
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        true,
+    )
+    .translate(types::Vec3::new(0.0, 0.0, 4.0));
+    let search_length = PositiveF64::new(5.0).unwrap();
+
+    let closest = sphere.closest_points(&cube, search_length).unwrap();
+    assert!((closest.distance - sphere.minimum_gap(&cube, search_length)).abs() < 1e-9);
+    assert!((closest.on_other.position.z - 3.0).abs() < 1e-9);
+    assert!((closest.on_self.position.z - 1.0).abs() < 1e-9);
+
+    // The witness points lie on the reported triangles
+    let swapped = cube.closest_points(&sphere, search_length).unwrap();
+    assert_eq!(swapped.on_self.position, closest.on_other.position);
+    assert_eq!(swapped.on_other.position, closest.on_self.position);
+    let coordinates = swapped.on_other.barycentric_coordinates;
+    assert!(coordinates.x >= 0.0 && coordinates.y >= 0.0 && coordinates.z >= 0.0);
+
+    // Intersecting surfaces touch
+    let overlapping = cube.translate(types::Vec3::new(0.0, 0.0, -3.5));
+    let touching = sphere.closest_points(&overlapping, search_length).unwrap();
+    assert_eq!(touching.distance, 0.0);
+    assert_eq!(touching.on_self.position, touching.on_other.position);
+
+    assert!(sphere
+        .closest_points(&cube, PositiveF64::new(1.5).unwrap())
+        .is_none());
+    assert!(sphere
+        .closest_points(&Manifold::new_empty(), search_length)
+        .is_none());
+}
+
+#[test]
+fn test_hausdorff_distance() {
+    let cube = Manifold::new_cuboid(
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        true,
+    );
+    let sphere = Manifold::new_sphere(
+        PositiveF64::new(1.0).unwrap(),
+        Some(PositiveI32::new(64).unwrap()),
+    );
+    let density = PositiveF64::new(200.0).unwrap();
+
+    // The cube's corners are furthest from the inscribed sphere
+    let distance = cube.hausdorff_distance(&sphere, density);
+    assert!((distance - (3.0f64.sqrt() - 1.0)).abs() < 0.01);
+    assert_eq!(
+        distance,
+        cube.one_sided_hausdorff_distance(&sphere, density)
+    );
+    // Every point of the sphere is close to a face of the cube
+    let inward = sphere.one_sided_hausdorff_distance(&cube, density);
+    assert!(inward < distance);
+    assert!(inward > 0.0);
+
+    assert!(cube.hausdorff_distance(&cube.clone(), density) < 1e-12);
+    assert_eq!(
+        cube.hausdorff_distance(&Manifold::new_empty(), density),
+        f64::INFINITY
+    );
+}
+
+#[test]
+fn test_approx_eq() {
+    let cube = Manifold::new_cuboid(
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        false,
+    );
+    let tolerance = PositiveF64::new(0.01).unwrap();
+
+    let nudged = cube.translate(types::Vec3::new(0.005, 0.0, 0.0));
+    assert!(cube.approx_eq(&nudged, tolerance));
+    manifold3d::assert_manifold_approx_eq!(cube, nudged, tolerance);
+
+    let moved = cube.translate(types::Vec3::new(0.05, 0.0, 0.0));
+    assert!(matches!(
+        cube.approx_difference(&moved, tolerance),
+        Some(manifold3d::manifold::ShapeDifference::BoundingBox { .. })
+    ));
+    manifold3d::assert_manifold_approx_ne!(cube, moved, tolerance, "moved by {}", 0.05);
+
+    let ring = cube.difference(
+        &Manifold::new_cylinder(
+            PositiveF64::new(2.0).unwrap(),
+            PositiveF64::new(0.1).unwrap(),
+            None::<PositiveF64>,
+            None::<PositiveI32>,
+            false,
+        )
+        .translate(types::Vec3::new(0.5, 0.5, -0.5)),
+    );
+    assert!(matches!(
+        cube.approx_difference(&ring, tolerance),
+        Some(manifold3d::manifold::ShapeDifference::Genus { left: 0, right: 1 })
+    ));
+}
+
+#[test]
+#[should_panic(expected = "surfaces deviate")]
+fn test_assert_manifold_approx_eq_reports_difference() {
+    let sphere = Manifold::new_sphere(
+        PositiveF64::new(1.0).unwrap(),
+        Some(PositiveI32::new(64).unwrap()),
+    );
+    // A dent in the sphere keeps its genus, volume and bounding box roughly intact
+    let dented = sphere.difference(
+        &Manifold::new_sphere(
+            PositiveF64::new(0.2).unwrap(),
+            Some(PositiveI32::new(32).unwrap()),
+        )
+        .translate(types::Vec3::new(0.577, 0.577, 0.577)),
+    );
+    manifold3d::assert_manifold_approx_eq!(sphere, dented, PositiveF64::new(0.02).unwrap());
+}
+
+#[test]
+fn test_wall_thickness() {
+    let block = Manifold::new_cuboid(
+        PositiveF64::new(4.0).unwrap(),
+        PositiveF64::new(4.0).unwrap(),
+        PositiveF64::new(4.0).unwrap(),
+        false,
+    );
+    // A pocket leaves a thin floor of 0.5 under it
+    let pocket = Manifold::new_cuboid(
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(4.0).unwrap(),
+        false,
+    )
+    .translate(types::Vec3::new(1.0, 1.0, 0.5));
+    let part = block.difference(&pocket).as_original();
+    let min_thickness = PositiveF64::new(0.8).unwrap();
+
+    let thickness = part.wall_thickness(min_thickness, types::NonNegativeI32::new(1).unwrap());
+    assert!((thickness.minimum - 0.5).abs() < 1e-9);
+    assert_eq!(thickness.manifold.properties_per_vertex_count(), 2);
+    assert!((thickness.manifold.volume() - part.volume()).abs() < 1e-9);
+
+    // The floor of the pocket and the bottom below it are thin
+    assert!(!thickness.thin_face_ids.is_empty());
+    let thin_area: f64 = thickness
+        .histogram
+        .iter()
+        .filter(|bin| bin.max <= min_thickness.get())
+        .map(|bin| bin.area)
+        .sum();
+    let total_area: f64 = thickness.histogram.iter().map(|bin| bin.area).sum();
+    assert!(thin_area >= 4.0 - 1e-9);
+    assert!((total_area - part.surface_area()).abs() < 1e-9);
+
+    let mesh = thickness.manifold.as_mesh64();
+    let properties = mesh.vertex_properties();
+    let properties_per_vertex = mesh.properties_per_vertex_count();
+    for vertex in properties.chunks_exact(properties_per_vertex) {
+        let thickness = vertex[4];
+        assert!(thickness > 0.0 && thickness <= 48.0f64.sqrt());
+        // The vertices of the pocket floor measure the floor
+        if vertex[2] == 0.5 {
+            assert!((thickness - 0.5).abs() < 1e-9);
+        }
+    }
+
+    let solid = block.wall_thickness(min_thickness, types::NonNegativeI32::new(0).unwrap());
+    assert!(solid.thin_face_ids.is_empty());
+    assert_eq!(solid.histogram.last().unwrap().triangle_count, 12);
+}
+
+#[test]
+fn test_overhang_analysis() {
+    let bar = Manifold::new_cuboid(
+        PositiveF64::new(4.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        false,
+    );
+    let post = Manifold::new_cuboid(
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        false,
+    );
+    // An L-shaped bracket: a post with an arm sticking out at the top
+    let bracket = post.union(&bar.translate(types::Vec3::new(0.0, 0.0, 2.0)));
+    let max_angle = types::NormalizedAngle::from_degrees(45.0);
+
+    let upright = bracket
+        .overhang_analysis(types::Vec3::new(0.0, 0.0, 2.0), max_angle)
+        .unwrap();
+    assert!((upright.overhang_area - 3.0).abs() < 1e-9);
+    assert!((upright.support.volume() - 6.0).abs() < 1e-9);
+    assert!(upright.support.intersection(&bracket).volume() < 1e-9);
+    let footprint_area = Manifold::extrude_polygons(
+        &upright.footprint,
+        PositiveF64::new(1.0).unwrap(),
+        PositiveI32::new(1).unwrap(),
+        0.0,
+        None::<types::Vec2>,
+    )
+    .unwrap()
+    .volume();
+    assert!((footprint_area - 3.0).abs() < 1e-9);
+
+    // Built on its side, with the post and the arm lying flat, nothing overhangs
+    let on_side = bracket
+        .overhang_analysis(types::Vec3::new(0.0, 1.0, 0.0), max_angle)
+        .unwrap();
+    assert_eq!(on_side.overhang_area, 0.0);
+    assert!(on_side.support.is_empty());
+    assert_eq!(on_side.footprint.count(), 0);
+
+    // The plate frame puts the lowest point at Z = 0
+    let on_plate = bracket.transform(on_side.plate_frame);
+    assert!(on_plate.bounding_box().min_point().z.abs() < 1e-9);
+
+    assert!(bracket
+        .overhang_analysis(types::Vec3::new(0.0, 0.0, 0.0), max_angle)
+        .is_err());
+}
+
+#[test]
+fn test_hollow() {
+    let sphere = Manifold::new_sphere(
+        PositiveF64::new(5.0).unwrap(),
+        Some(PositiveI32::new(64).unwrap()),
+    );
+    let drain = manifold3d::manifold::DrainHole::new(
+        Point3::new(0.0, 0.0, -5.0),
+        types::Vec3::new(0.0, 0.0, 1.0),
+        PositiveF64::new(1.0).unwrap(),
+    );
+
+    let hollowing = sphere.hollow(PositiveF64::new(1.0).unwrap(), &[drain]);
+    let shell = &hollowing.manifold;
+    assert_eq!(hollowing.volume_before, sphere.volume());
+    assert_eq!(hollowing.volume_after, shell.volume());
+    // The cavity is a sphere of radius 4
+    let cavity_volume = 4.0 / 3.0 * std::f64::consts::PI * 64.0;
+    assert!((hollowing.volume_saved() - cavity_volume).abs() < 0.03 * cavity_volume);
+
+    assert!(shell.contains_point(Point3::new(4.5, 0.0, 0.0)));
+    assert!(!shell.contains_point(Point3::new(0.0, 0.0, 0.0)));
+    assert!(!shell.contains_point(Point3::new(3.5, 0.0, 0.0)));
+    // The drain hole pierces the wall
+    assert!(!shell.contains_point(Point3::new(0.0, 0.0, -4.5)));
+    assert!(shell.contains_point(Point3::new(0.0, 0.8, -4.5)));
+    assert_eq!(shell.decompose().count(), 1);
+
+    // A part thinner than twice the wall stays solid
+    let plate = Manifold::new_cuboid(
+        PositiveF64::new(10.0).unwrap(),
+        PositiveF64::new(10.0).unwrap(),
+        PositiveF64::new(1.5).unwrap(),
+        true,
+    );
+    let hollowing = plate.hollow(PositiveF64::new(1.0).unwrap(), &[]);
+    assert!(hollowing.volume_saved().abs() < 1e-9);
+}
+
+#[test]
+fn test_offset() {
+    let options = manifold3d::manifold::OffsetOptions::default();
+    let sphere = Manifold::new_sphere(
+        PositiveF64::new(5.0).unwrap(),
+        Some(PositiveI32::new(64).unwrap()),
+    );
+    let shrunk = sphere.offset(-0.5, &options);
+    let radius = shrunk.bounding_box().dimensions().x / 2.0;
+    assert!((radius - 4.5).abs() < 0.1);
+
+    // A non-convex L shape grows through the level set
+    let cube = Manifold::new_cuboid(
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        false,
+    );
+    let l_shape = cube
+        .scale(types::Vec3::new(4.0, 1.0, 1.0))
+        .union(&cube.scale(types::Vec3::new(1.0, 4.0, 1.0)));
+    let grown = l_shape.offset(0.25, &options);
+    let dimensions = grown.bounding_box().dimensions();
+    assert!((dimensions.x - 4.5).abs() < 0.05);
+    assert!((dimensions.z - 1.5).abs() < 0.05);
+    assert!(grown.contains_point(Point3::new(1.1, 1.1, 0.5)));
+    assert!(!grown.contains_point(Point3::new(1.5, 1.5, 0.5)));
+
+    // A peg shrunk by the clearance slides into a hole of its original size
+    let peg = Manifold::new_cylinder(
+        PositiveF64::new(4.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        None::<PositiveF64>,
+        Some(PositiveI32::new(64).unwrap()),
+        true,
+    );
+    let clearance = 0.2;
+    let fitted = peg.offset(-clearance, &options);
+    let hole = peg.scale(types::Vec3::new(2.0, 2.0, 0.5)).difference(&peg);
+    let gap = fitted.closest_points(&hole, PositiveF64::new(1.0).unwrap());
+    assert!(gap.is_some_and(|gap| (gap.distance - clearance).abs() < 0.05));
+
+    assert!(sphere.offset(-6.0, &options).is_empty());
+    assert_eq!(sphere.offset(0.0, &options).volume(), sphere.volume());
+}
+
+#[test]
+fn test_crease_halfedges() {
+    let cylinder = Manifold::new_cylinder(
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        None::<PositiveF64>,
+        Some(PositiveI32::new(8).unwrap()),
+        false,
+    );
+    let mesh_gl = cylinder.as_mesh();
+    let options = manifold3d::CreaseOptions::default();
+    // The rims of the caps at 90° and the sides at 45°
+    let creases = mesh_gl.crease_halfedges(&options, |angle| 1.0 - angle / 90.0);
+    assert_eq!(creases.len(), 2 * (8 + 8 + 8));
+    assert!(creases.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    let sharp = creases
+        .iter()
+        .filter(|(_, smoothness)| *smoothness == 0.0)
+        .count();
+    assert_eq!(sharp, 2 * (8 + 8));
+    assert!(creases
+        .iter()
+        .all(|(_, smoothness)| *smoothness == 0.0 || (smoothness - 0.5).abs() < 1e-6));
+
+    // Refined triangles keep the face ID of the triangle they are split from
+    let edge_split_count =
+        manifold3d::manifold::EdgeSplitCount::new(PositiveI32::new(4).unwrap()).unwrap();
+    let curved = mesh_gl
+        .smooth(None)
+        .unwrap()
+        .refine_via_edge_splits(edge_split_count)
+        .as_mesh();
+    let options = manifold3d::CreaseOptions {
+        min_angle: types::NormalizedAngle::from_degrees(0.1),
+        face_boundaries_only: false,
+    };
+    let all = curved.crease_halfedges(&options, |_| 0.0);
+    let boundaries = curved.crease_halfedges(
+        &manifold3d::CreaseOptions {
+            face_boundaries_only: true,
+            ..options
+        },
+        |_| 0.0,
+    );
+    assert!(!boundaries.is_empty());
+    assert!(boundaries.len() < all.len());
+    let face_ids = curved.face_ids();
+    let vertex_indices = curved.vertex_indices();
+    let positions = curved.vertex_properties();
+    let properties_per_vertex = curved.properties_per_vertex_count() as usize;
+    let position = |vertex: u32| {
+        let offset = vertex as usize * properties_per_vertex;
+        [0, 1, 2].map(|axis| positions[offset + axis].to_bits())
+    };
+    for &(halfedge, _) in &boundaries {
+        let next = halfedge - halfedge % 3 + (halfedge + 1) % 3;
+        let (start, end) = (
+            position(vertex_indices[halfedge]),
+            position(vertex_indices[next]),
+        );
+        let pair = (0..vertex_indices.len())
+            .find(|&other| {
+                let other_next = other - other % 3 + (other + 1) % 3;
+                position(vertex_indices[other]) == end
+                    && position(vertex_indices[other_next]) == start
+            })
+            .unwrap();
+        assert_ne!(face_ids[halfedge / 3], face_ids[pair / 3]);
+    }
+}
+
+#[test]
+fn test_fillet_and_chamfer() {
+    let cube = Manifold::new_cuboid(
+        PositiveF64::new(10.0).unwrap(),
+        PositiveF64::new(10.0).unwrap(),
+        PositiveF64::new(10.0).unwrap(),
+        true,
+    );
+    let radius = PositiveF64::new(1.0).unwrap();
+
+    // Select the edge at X = Y = 5 by the face IDs of its two sides
+    let mesh_gl = cube.as_mesh64();
+    let positions = mesh_gl.vertex_properties();
+    let properties_per_vertex = mesh_gl.properties_per_vertex_count();
+    let face_ids_on = |axis: usize| {
+        mesh_gl
+            .vertex_indices()
+            .chunks_exact(3)
+            .zip(mesh_gl.face_ids())
+            .filter(|(triangle, _)| {
+                triangle
+                    .iter()
+                    .all(|&vertex| positions[vertex as usize * properties_per_vertex + axis] == 5.0)
+            })
+            .map(|(_, face_id)| face_id)
+            .collect::<Vec<_>>()
+    };
+    let (x_faces, y_faces) = (face_ids_on(0), face_ids_on(1));
+    let edges = manifold3d::manifold::EdgeSelection {
+        face_id_pairs: Some(
+            x_faces
+                .iter()
+                .flat_map(|&a| y_faces.iter().map(move |&b| (a, b)))
+                .collect(),
+        ),
+        ..Default::default()
+    };
+    let rounded = cube.fillet(&edges, radius);
+    assert!(!rounded.contains_point(Point3::new(4.9, 4.9, 0.0)));
+    assert!(rounded.contains_point(Point3::new(4.9, -4.9, 0.0)));
+    // Between the exact round and a chamfer, depending on the segments of the arc
+    let removed = cube.volume() - rounded.volume();
+    assert!(removed >= 10.0 * (1.0 - std::f64::consts::PI / 4.0) - 1e-9);
+    assert!(removed < 10.0 * 0.5);
+    assert_eq!(rounded.genus(), 0);
+
+    let nothing = manifold3d::manifold::EdgeSelection {
+        face_id_pairs: Some(Vec::new()),
+        ..Default::default()
+    };
+    assert_eq!(cube.fillet(&nothing, radius).volume(), cube.volume());
+
+    // The facets of a sphere meet at small angles, which the default does not select
+    let sphere = Manifold::new_sphere(
+        PositiveF64::new(5.0).unwrap(),
+        Some(PositiveI32::new(64).unwrap()),
+    );
+    let filleted = sphere.fillet(&manifold3d::manifold::EdgeSelection::default(), radius);
+    assert_eq!(filleted.triangle_count(), sphere.triangle_count());
+
+    // The concave edge of an L shape is filled, selected by a region around it
+    let unit = Manifold::new_cuboid(
+        PositiveF64::new(10.0).unwrap(),
+        PositiveF64::new(10.0).unwrap(),
+        PositiveF64::new(10.0).unwrap(),
+        false,
+    );
+    let l_shape = unit
+        .scale(types::Vec3::new(4.0, 1.0, 1.0))
+        .union(&unit.scale(types::Vec3::new(1.0, 4.0, 1.0)));
+    let corner = manifold3d::manifold::EdgeSelection {
+        region: Some(BoundingBox::new(
+            Point3::new(9.0, 9.0, -1.0),
+            Point3::new(11.0, 11.0, 11.0),
+        )),
+        ..Default::default()
+    };
+    let radius = PositiveF64::new(2.5).unwrap();
+    let filled = l_shape.fillet(&corner, radius);
+    let added = filled.volume() - l_shape.volume();
+    assert!(added >= 10.0 * 6.25 * (1.0 - std::f64::consts::PI / 4.0) - 1e-9);
+    assert!(added < 1.1 * 10.0 * 6.25 * (1.0 - std::f64::consts::PI / 4.0));
+    assert!(filled.contains_point(Point3::new(10.5, 10.5, 5.0)));
+    assert!(!filled.contains_point(Point3::new(12.0, 12.0, 5.0)));
+    assert_eq!(filled.bounding_box().dimensions().x, 40.0);
+
+    let beveled = l_shape.chamfer(&corner, radius);
+    assert!((beveled.volume() - l_shape.volume() - 10.0 * 3.125).abs() < 1e-9);
+}
+
+#[test]
+fn test_more_primitives() {
+    use manifold3d::Error;
+    use std::f64::consts::PI;
+
+    let segments = Some(PositiveI32::new(128).unwrap());
+    let torus = Manifold::new_torus(
+        PositiveF64::new(3.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        segments,
+    )
+    .unwrap();
+    assert!((torus.volume() - 2.0 * PI * PI * 3.0).abs() < 0.01 * torus.volume());
+    assert!((torus.bounding_box().dimensions().x - 8.0).abs() < 1e-9);
+    assert!(matches!(
+        Manifold::new_torus(
+            PositiveF64::new(1.0).unwrap(),
+            PositiveF64::new(1.0).unwrap(),
+            segments,
+        ),
+        Err(Error::InvalidConstruction)
+    ));
+
+    let capsule = Manifold::new_capsule(
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        segments,
+        true,
+    );
+    let expected = PI * 2.0 + 4.0 / 3.0 * PI;
+    assert!((capsule.volume() - expected).abs() < 0.01 * expected);
+    assert_eq!(capsule.bounding_box().min_point().z, -2.0);
+
+    let rounded = Manifold::new_rounded_cuboid(
+        PositiveF64::new(4.0).unwrap(),
+        PositiveF64::new(4.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        segments,
+        false,
+    )
+    .unwrap();
+    assert_eq!(
+        rounded.bounding_box().min_point(),
+        Point3::new(0.0, 0.0, 0.0)
+    );
+    // The flat 2x2 square between the sphere centers grown by the radius
+    let expected = 2.0 * 4.0 + PI * 4.0 + 4.0 / 3.0 * PI;
+    assert!((rounded.volume() - expected).abs() < 0.01 * expected);
+    assert!(matches!(
+        Manifold::new_rounded_cuboid(
+            PositiveF64::new(4.0).unwrap(),
+            PositiveF64::new(4.0).unwrap(),
+            PositiveF64::new(1.0).unwrap(),
+            PositiveF64::new(1.0).unwrap(),
+            segments,
+            false,
+        ),
+        Err(Error::InvalidConstruction)
+    ));
+
+    let cone = Manifold::new_cone(
+        PositiveF64::new(3.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        segments,
+        false,
+    );
+    assert!((cone.volume() - PI).abs() < 0.01 * PI);
+    assert!(cone.contains_point(Point3::new(0.0, 0.0, 2.9)));
+    assert!(!cone.contains_point(Point3::new(0.2, 0.0, 2.9)));
+
+    let radius = PositiveF64::new(2.0).unwrap();
+    let octahedron = Manifold::new_octahedron(radius);
+    assert!((octahedron.volume() - 4.0 / 3.0 * 8.0).abs() < 1e-9);
+    // The volumes of the unit polyhedra scaled by the cube of the radius
+    let icosahedron = Manifold::new_icosahedron(radius);
+    assert!((icosahedron.volume() - 2.536_150_710 * 8.0).abs() < 1e-6);
+    let dodecahedron = Manifold::new_dodecahedron(radius);
+    assert!((dodecahedron.volume() - 2.785_163_863 * 8.0).abs() < 1e-6);
+    assert_eq!(dodecahedron.genus(), 0);
+
+    let points = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ];
+    let faces = [vec![0, 2, 1], vec![0, 1, 3], vec![1, 2, 3], vec![2, 0, 3]];
+    let tetrahedron = Manifold::new_polyhedron(&points, &faces).unwrap();
+    assert!((tetrahedron.volume() - 1.0 / 6.0).abs() < 1e-9);
+    assert!(matches!(
+        Manifold::new_polyhedron(&points, &[vec![0, 1]]),
+        Err(Error::InvalidConstruction)
+    ));
+    assert!(matches!(
+        Manifold::new_polyhedron(&points, &[vec![0, 1, 7]]),
+        Err(Error::VertexIndexOutOfBounds)
+    ));
+    assert!(matches!(
+        Manifold::new_polyhedron(&points, &faces[..3]),
+        Err(Error::NotManifold)
+    ));
+}
+
+#[test]
+fn test_sweep() {
+    use manifold3d::manifold::{SweepError, SweepOptions};
+    use manifold3d::types::{Point2, Vec2};
+    use manifold3d::{Polygons, SimplePolygon};
+    use std::f64::consts::PI;
+
+    let circle = |radius: f64, segments: usize| {
+        Polygons::from_simple_polygons(vec![SimplePolygon::new_from_points(
+            (0..segments)
+                .map(|i| {
+                    let angle = 2.0 * PI * i as f64 / segments as f64;
+                    Point2::new(radius * angle.cos(), radius * angle.sin())
+                })
+                .collect(),
+        )])
+    };
+    let polygon_area = |segments: usize| segments as f64 / 2.0 * (2.0 * PI / segments as f64).sin();
+
+    // A circle swept around a circle is a torus
+    let ring = (0..128)
+        .map(|i| {
+            let angle = 2.0 * PI * i as f64 / 128.0;
+            Point3::new(3.0 * angle.cos(), 3.0 * angle.sin(), 1.0)
+        })
+        .collect::<Vec<_>>();
+    let closed = SweepOptions {
+        closed: true,
+        ..SweepOptions::default()
+    };
+    let torus = Manifold::sweep(&circle(1.0, 32), &ring, &closed).unwrap();
+    let expected = polygon_area(32) * 2.0 * PI * 3.0;
+    assert!((torus.volume() - expected).abs() < 0.01 * expected);
+    assert_eq!(torus.genus(), 1);
+    assert!(torus.contains_point(Point3::new(0.0, -3.5, 1.0)));
+    assert!(!torus.contains_point(Point3::new(0.0, 0.0, 1.0)));
+    assert!((torus.bounding_box().dimensions().z - 2.0).abs() < 0.01);
+
+    // An open arc is capped at both ends
+    let arc = &ring[..65];
+    let half = Manifold::sweep(&circle(1.0, 32), arc, &SweepOptions::default()).unwrap();
+    assert_eq!(half.genus(), 0);
+    assert!((half.volume() - expected / 2.0).abs() < 0.01 * expected);
+
+    let square = Polygons::from_simple_polygons(vec![SimplePolygon::new_from_points(vec![
+        Point2::new(-1.0, -1.0),
+        Point2::new(1.0, -1.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(-1.0, 1.0),
+    ])]);
+    let line = [Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.0, 3.0)];
+    let frustum = Manifold::sweep(
+        &square,
+        &line,
+        &SweepOptions {
+            scales: vec![Vec2::new(1.0, 1.0), Vec2::new(2.0, 2.0)],
+            ..SweepOptions::default()
+        },
+    )
+    .unwrap();
+    assert!((frustum.volume() - 3.0 * (4.0 + 16.0 + 8.0) / 3.0).abs() < 1e-9);
+    assert!((frustum.bounding_box().dimensions().x - 4.0).abs() < 1e-9);
+
+    let column = (0..=10)
+        .map(|i| Point3::new(0.0, 0.0, i as f64))
+        .collect::<Vec<_>>();
+    let twisted = Manifold::sweep(
+        &square,
+        &column,
+        &SweepOptions {
+            twist_degrees: (0..=10).map(|i| 4.5 * i as f64).collect(),
+            ..SweepOptions::default()
+        },
+    )
+    .unwrap();
+    // The top is turned by 45°, so its corners lie on the axes
+    let corner = 2f64.sqrt() - 0.01;
+    assert!(twisted.contains_point(Point3::new(corner, 0.0, 9.99)));
+    assert!(!twisted.contains_point(Point3::new(corner, 0.0, 0.01)));
+    // Twisting a straight sweep matches a twisted extrusion
+    let extruded = square
+        .extrude(
+            PositiveF64::new(10.0).unwrap(),
+            PositiveI32::new(9).unwrap(),
+            45.0,
+            None::<Vec2>,
+        )
+        .unwrap();
+    assert!((twisted.volume() - extruded.volume()).abs() < 1e-9);
+
+    assert_eq!(
+        Manifold::sweep(&square, &line, &closed).err(),
+        Some(SweepError::TooFewPoints {
+            minimum: 3,
+            actual: 2
+        })
+    );
+    assert_eq!(
+        Manifold::sweep(
+            &square,
+            &line,
+            &SweepOptions {
+                twist_degrees: vec![0.0],
+                ..SweepOptions::default()
+            }
+        )
+        .err(),
+        Some(SweepError::OptionLengthMismatch {
+            option: "twist angles",
+            path: 2,
+            actual: 1
+        })
+    );
+    assert_eq!(
+        Manifold::sweep(
+            &square,
+            &[line[0], line[0], line[1]],
+            &SweepOptions::default()
+        )
+        .err(),
+        Some(SweepError::DegenerateSegment(0))
+    );
+}
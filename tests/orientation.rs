@@ -0,0 +1,66 @@
+use manifold3d::orientation::{self, OrientationCriteria};
+use manifold3d::types::{PositiveF64, Vec3};
+use manifold3d::Manifold;
+
+#[test]
+fn test_optimize_prefers_flat_orientation_of_bracket() {
+    // An L-shaped bracket standing upright needs support under its arm
+    let bracket = Manifold::new_cuboid(
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(3.0).unwrap(),
+        false,
+    )
+    .union(
+        &Manifold::new_cuboid(
+            PositiveF64::new(4.0).unwrap(),
+            PositiveF64::new(1.0).unwrap(),
+            PositiveF64::new(1.0).unwrap(),
+            false,
+        )
+        .translate(Vec3::new(0.0, 0.0, 2.0)),
+    );
+    let upright = orientation::optimize(&bracket, &OrientationCriteria::default())
+        .into_iter()
+        .find(|candidate| candidate.build_direction == Vec3::new(0.0, 0.0, 1.0))
+        .unwrap();
+    assert!((upright.support_volume - 6.0).abs() < 1e-9);
+
+    let ranked = orientation::optimize(&bracket, &OrientationCriteria::default());
+    assert!(ranked.windows(2).all(|pair| pair[0].score <= pair[1].score));
+    // Lying on its side, the bracket needs no support and rests on its largest face
+    let best = &ranked[0];
+    assert_eq!(best.build_direction.y.abs(), 1.0);
+    assert_eq!(best.support_volume, 0.0);
+    assert!((best.contact_area - 6.0).abs() < 1e-9);
+    assert!((best.build_height - 1.0).abs() < 1e-9);
+
+    let placed = bracket.transform(best.transform);
+    assert!(placed.bounding_box().min_point().z.abs() < 1e-9);
+    assert!((placed.volume() - bracket.volume()).abs() < 1e-9);
+}
+
+#[test]
+fn test_optimize_weights() {
+    let slab = Manifold::new_cuboid(
+        PositiveF64::new(4.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(1.0).unwrap(),
+        false,
+    );
+    // Only the height counts, so the slab lies flat on its largest face
+    let criteria = OrientationCriteria {
+        overhang_area_weight: 0.0,
+        contact_area_weight: 0.0,
+        support_volume_weight: 0.0,
+        sampled_direction_count: 0,
+        ..OrientationCriteria::default()
+    };
+    let ranked = orientation::optimize(&slab, &criteria);
+    // The six faces of the hull
+    assert_eq!(ranked.len(), 6);
+    assert!((ranked[0].build_height - 1.0).abs() < 1e-9);
+    assert!((ranked[5].build_height - 4.0).abs() < 1e-9);
+
+    assert!(orientation::optimize(&Manifold::new_empty(), &criteria).is_empty());
+}
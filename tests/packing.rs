@@ -0,0 +1,115 @@
+use manifold3d::packing::{self, PackingOptions};
+use manifold3d::types::{NonNegativeF64, PositiveF64, Vec3};
+use manifold3d::Manifold;
+
+#[test]
+fn test_pack_keeps_spacing_and_plate() {
+    let parts = vec![
+        Manifold::new_cuboid(
+            PositiveF64::new(30.0).unwrap(),
+            PositiveF64::new(10.0).unwrap(),
+            PositiveF64::new(5.0).unwrap(),
+            false,
+        )
+        .translate(Vec3::new(-50.0, 20.0, 3.0)),
+        Manifold::new_cuboid(
+            PositiveF64::new(10.0).unwrap(),
+            PositiveF64::new(10.0).unwrap(),
+            PositiveF64::new(10.0).unwrap(),
+            false,
+        ),
+        Manifold::new_cuboid(
+            PositiveF64::new(20.0).unwrap(),
+            PositiveF64::new(20.0).unwrap(),
+            PositiveF64::new(2.0).unwrap(),
+            false,
+        ),
+        Manifold::new_cylinder(
+            PositiveF64::new(8.0).unwrap(),
+            PositiveF64::new(6.0).unwrap(),
+            None::<PositiveF64>,
+            None::<manifold3d::types::PositiveI32>,
+            true,
+        ),
+        Manifold::new_cuboid(
+            PositiveF64::new(15.0).unwrap(),
+            PositiveF64::new(5.0).unwrap(),
+            PositiveF64::new(5.0).unwrap(),
+            false,
+        ),
+    ];
+    let options = PackingOptions {
+        plate_width: PositiveF64::new(50.0).unwrap(),
+        plate_depth: PositiveF64::new(50.0).unwrap(),
+        spacing: NonNegativeF64::new(2.0).unwrap(),
+        rotation_count: 4,
+    };
+
+    let packing = packing::pack(&parts, &options);
+    assert!(packing.unplaced.is_empty());
+    assert_eq!(
+        packing
+            .placements
+            .iter()
+            .map(|placement| placement.index)
+            .collect::<Vec<_>>(),
+        vec![0, 1, 2, 3, 4]
+    );
+
+    let placed = packing
+        .placements
+        .iter()
+        .map(|placement| parts[placement.index].transform(placement.transform))
+        .collect::<Vec<_>>();
+    for (index, part) in placed.iter().enumerate() {
+        let bounding_box = part.bounding_box();
+        assert!(bounding_box.min_point().x >= -1e-9 && bounding_box.min_point().y >= -1e-9);
+        assert!(bounding_box.max_point().x <= 50.0 + 1e-9);
+        assert!(bounding_box.max_point().y <= 50.0 + 1e-9);
+        assert!(bounding_box.min_point().z.abs() < 1e-9);
+        assert!((part.volume() - parts[index].volume()).abs() < 1e-6);
+        for other in &placed[index + 1..] {
+            assert!(part.minimum_gap(other, PositiveF64::new(10.0).unwrap()) >= 2.0 - 1e-9);
+        }
+    }
+
+    let total_volume: f64 = parts.iter().map(Manifold::volume).sum();
+    assert!((packing.composed.volume() - total_volume).abs() < 1e-6);
+}
+
+#[test]
+fn test_pack_rotates_and_reports_unplaced_parts() {
+    // The bar only fits onto the plate when rotated by 90°
+    let bar = Manifold::new_cuboid(
+        PositiveF64::new(5.0).unwrap(),
+        PositiveF64::new(40.0).unwrap(),
+        PositiveF64::new(5.0).unwrap(),
+        false,
+    );
+    let options = PackingOptions {
+        plate_width: PositiveF64::new(50.0).unwrap(),
+        plate_depth: PositiveF64::new(10.0).unwrap(),
+        spacing: NonNegativeF64::new(1.0).unwrap(),
+        rotation_count: 4,
+    };
+    let packing = packing::pack(&[bar.clone(), bar, Manifold::new_empty()], &options);
+    assert_eq!(packing.placements.len(), 1);
+    assert_eq!(packing.placements[0].rotation_degrees % 180.0, 90.0);
+    assert_eq!(packing.unplaced, vec![1, 2]);
+
+    let unrotated = PackingOptions {
+        rotation_count: 1,
+        ..options
+    };
+    let packing = packing::pack(
+        &[Manifold::new_cuboid(
+            PositiveF64::new(5.0).unwrap(),
+            PositiveF64::new(40.0).unwrap(),
+            PositiveF64::new(5.0).unwrap(),
+            false,
+        )],
+        &unrotated,
+    );
+    assert!(packing.placements.is_empty());
+    assert!(packing.composed.is_empty());
+}
@@ -0,0 +1,148 @@
+#![cfg(feature = "serde")]
+
+use manifold3d::manifold::BooleanOperation;
+use manifold3d::types::{
+    Matrix4x3, NonNegativeF64, NonNegativeI32, NormalizedAngle, Point2, Point3, PositiveF64,
+    PositiveI32, Vec3,
+};
+use manifold3d::{FillRule, Manifold, MeshGL, MeshGLBuffers, Polygons, SimplePolygon};
+
+#[test]
+fn test_serde_round_trip_of_value_types() {
+    let point = Point3::new(1.0, 2.5, -3.0);
+    let json = serde_json::to_string(&point).unwrap();
+    assert_eq!(json, r#"{"x":1.0,"y":2.5,"z":-3.0}"#);
+    assert_eq!(serde_json::from_str::<Point3>(&json).unwrap(), point);
+
+    let matrix = Matrix4x3::new([
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+        Vec3::new(0.0, 0.0, 1.0),
+        Vec3::new(4.0, 5.0, 6.0),
+    ]);
+    let json = serde_json::to_string(&matrix).unwrap();
+    assert_eq!(serde_json::from_str::<Matrix4x3>(&json).unwrap(), matrix);
+
+    let angle = NormalizedAngle::from_degrees(90.0);
+    assert_eq!(serde_json::to_string(&angle).unwrap(), "90.0");
+    assert_eq!(
+        serde_json::from_str::<NormalizedAngle>("450.0").unwrap(),
+        angle
+    );
+
+    let json = serde_json::to_string(&BooleanOperation::Subtract).unwrap();
+    assert_eq!(
+        serde_json::from_str::<BooleanOperation>(&json).unwrap(),
+        BooleanOperation::Subtract
+    );
+    let json = serde_json::to_string(&FillRule::EvenOdd).unwrap();
+    assert_eq!(
+        serde_json::from_str::<FillRule>(&json).unwrap(),
+        FillRule::EvenOdd
+    );
+}
+
+#[test]
+fn test_serde_validates_constrained_numbers() {
+    assert_eq!(
+        serde_json::to_string(&PositiveF64::new(2.5).unwrap()).unwrap(),
+        "2.5"
+    );
+    assert_eq!(serde_json::from_str::<PositiveI32>("3").unwrap(), 3);
+    assert!(serde_json::from_str::<PositiveF64>("0.0").is_err());
+    assert!(serde_json::from_str::<PositiveI32>("-1").is_err());
+
+    assert_eq!(
+        serde_json::from_str::<NonNegativeF64>("0.0").unwrap().get(),
+        0.0
+    );
+    assert!(serde_json::from_str::<NonNegativeF64>("-0.5").is_err());
+}
+
+#[test]
+fn test_serde_round_trip_of_polygons() {
+    let polygons = Polygons::from_simple_polygons(vec![
+        SimplePolygon::new_from_points(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(0.0, 1.0),
+        ]),
+        SimplePolygon::new_from_points(vec![Point2::new(2.0, 2.0), Point2::new(3.0, 2.0)]),
+    ]);
+
+    let json = serde_json::to_string(&polygons).unwrap();
+    assert_eq!(
+        json,
+        r#"[[{"x":0.0,"y":0.0},{"x":1.0,"y":0.0},{"x":0.0,"y":1.0}],[{"x":2.0,"y":2.0},{"x":3.0,"y":2.0}]]"#
+    );
+
+    let deserialized = serde_json::from_str::<Polygons>(&json).unwrap();
+    assert_eq!(deserialized.count(), 2);
+    assert_eq!(
+        deserialized.get(1).unwrap().as_vec(),
+        polygons.get(1).unwrap().as_vec()
+    );
+}
+
+#[test]
+fn test_serde_round_trip_of_mesh_gl() {
+    let manifold = Manifold::new_cuboid(
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(3.0).unwrap(),
+        true,
+    );
+    let mesh_gl = manifold.as_mesh();
+
+    let json = serde_json::to_string(&mesh_gl).unwrap();
+    let deserialized = serde_json::from_str::<MeshGL>(&json).unwrap();
+
+    assert_eq!(
+        deserialized.vertex_properties(),
+        mesh_gl.vertex_properties()
+    );
+    assert_eq!(deserialized.vertex_indices(), mesh_gl.vertex_indices());
+    let restored = Manifold::from_mesh_gl(&deserialized).unwrap();
+    assert_eq!(restored.volume(), manifold.volume());
+
+    // Every buffer is serialized, including those a mesh cannot be given again
+    let buffers = serde_json::from_str::<MeshGLBuffers>(&json).unwrap();
+    assert_eq!(buffers, mesh_gl.buffers());
+    assert!(!buffers.face_ids.is_empty());
+    assert!(!buffers.run_original_ids.is_empty());
+
+    let invalid = json.replace(
+        r#""properties_per_vertex":3"#,
+        r#""properties_per_vertex":2"#,
+    );
+    assert!(serde_json::from_str::<MeshGL>(&invalid).is_err());
+}
+
+#[test]
+fn test_serde_round_trip_of_mesh_gl_with_property_seams() {
+    // Flat normals split the vertices at every edge of the cube into several property
+    // vertices sharing a position
+    let manifold = Manifold::new_cuboid(
+        PositiveF64::new(1.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+        PositiveF64::new(3.0).unwrap(),
+        true,
+    )
+    .calculate_normals(
+        NonNegativeI32::new(0).unwrap(),
+        NormalizedAngle::from_degrees(30.0),
+    );
+    let mesh_gl = manifold.as_mesh();
+    assert!(mesh_gl.mesh_merge_count() > 0);
+
+    let json = serde_json::to_string(&mesh_gl).unwrap();
+    let deserialized = serde_json::from_str::<MeshGL>(&json).unwrap();
+
+    assert_eq!(
+        deserialized.merge_from_vertex_indices(),
+        mesh_gl.merge_from_vertex_indices()
+    );
+    let restored = Manifold::from_mesh_gl(&deserialized).unwrap();
+    assert_eq!(restored.volume(), manifold.volume());
+    assert_eq!(restored.genus(), 0);
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "text")]
+
+use manifold3d::text::{Font, FontError, HorizontalAlignment, TextOptions, VerticalAlignment};
+use manifold3d::types::{PositiveF64, PositiveI32, Vec2};
+
+/// A font with the glyphs of space, H, O and i, drawn for these tests, see
+/// `tests/fonts/OFL.txt` for its license.
+const FONT: &[u8] = include_bytes!("fonts/ManifoldTest-Regular.ttf");
+
+fn bounds(polygons: &manifold3d::Polygons) -> (f64, f64, f64, f64) {
+    polygons
+        .as_vec()
+        .iter()
+        .flat_map(|polygon| polygon.as_vec())
+        .fold(
+            (
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+            ),
+            |(min_x, max_x, min_y, max_y), point| {
+                (
+                    min_x.min(point.x),
+                    max_x.max(point.x),
+                    min_y.min(point.y),
+                    max_y.max(point.y),
+                )
+            },
+        )
+}
+
+#[test]
+fn test_invalid_fonts() {
+    assert!(matches!(
+        Font::from_bytes(vec![0u8; 16]),
+        Err(FontError::InvalidFont(_))
+    ));
+    assert!(matches!(
+        Font::from_path("/nonexistent/font.ttf"),
+        Err(FontError::Io(_))
+    ));
+}
+
+#[test]
+fn test_text_layout() {
+    let font = Font::from_bytes(FONT.to_vec()).unwrap();
+    let options = TextOptions::default();
+
+    // The outline of an O with its hole
+    let letter = font.text("O", &options);
+    assert_eq!(letter.count(), 2);
+    let solid = letter
+        .extrude(
+            PositiveF64::new(1.0).unwrap(),
+            PositiveI32::new(1).unwrap(),
+            0.0,
+            None::<Vec2>,
+        )
+        .unwrap();
+    assert_eq!(solid.genus(), 1);
+    let (_, _, min_y, max_y) = bounds(&letter);
+    assert!(min_y > -1.0 && max_y > 6.0 && max_y < 10.0);
+
+    assert_eq!(font.text(" ", &options).count(), 0);
+
+    let centered = font.text(
+        "Hi\nHi",
+        &TextOptions {
+            horizontal_alignment: HorizontalAlignment::Center,
+            vertical_alignment: VerticalAlignment::Middle,
+            ..options
+        },
+    );
+    assert_eq!(centered.count(), 6);
+    let (min_x, max_x, min_y, max_y) = bounds(&centered);
+    assert!((min_x + max_x).abs() < 0.5);
+    assert!((min_y + max_y).abs() < 1.0);
+
+    let right = font.text(
+        "H",
+        &TextOptions {
+            horizontal_alignment: HorizontalAlignment::Right,
+            vertical_alignment: VerticalAlignment::Top,
+            ..options
+        },
+    );
+    let (_, max_x, _, max_y) = bounds(&right);
+    assert!(max_x < 0.0 && max_x > -2.0);
+    assert!(max_y < 0.0);
+}